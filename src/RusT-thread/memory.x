@@ -8,6 +8,36 @@ MEMORY
    /* NOTE 1 K = 1 KiBi = 1024 bytes */ 
 }
 
+/* CCMRAM is not used by any output section; the whole region is handed to
+   the memheap subsystem (see rtthread_rt::mem::memheap). */
+_sccmheap = ORIGIN(CCMRAM);
+_eccmheap = ORIGIN(CCMRAM) + LENGTH(CCMRAM);
+
+/* Size reserved for the main (MSP) stack at the top of RAM. The MSP is used
+   by main() before the scheduler starts and by every exception handler. */
+_stack_size = 8K;
+
+/* Size of the SRAM region handed to the memheap subsystem. It sits between
+   the global heap and the main stack reservation and is not part of the
+   global heap (see rtthread_rt::mem::memheap). */
+_sramheap_size = 16K;
+_esramheap = _stack_start - _stack_size;
+_ssramheap = _esramheap - _sramheap_size;
+
+/* The heap starts at __sheap (end of .data/.bss/.uninit, provided by
+   cortex-m-rt) and ends right below the memheap SRAM region
+   (see rtthread_rt::mem::allocator::init_heap). */
+_heap_end = _ssramheap;
+
+/* Minimum heap size; linking fails if the statics grow into it. */
+_heap_min_size = 16K;
+
+ASSERT(__sheap + _heap_min_size <= _heap_end, "
+ERROR: the heap (__sheap.._heap_end) is smaller than _heap_min_size.
+The statics in .data/.bss have grown into the heap, or the main stack
+reservation (_stack_size) or the memheap SRAM region (_sramheap_size)
+is too large.");
+
 /* This is where the call stack will be allocated. */
 /* The stack is of the full descending type. */
 /* You may want to use this variable to locate the call stack and static
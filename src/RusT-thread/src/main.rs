@@ -86,6 +86,8 @@ fn init() {
     rtthread_rt::timer::timer::rt_system_timer_init(syst, &clocks);
     // 内存分配器初始化
     rtthread_rt::mem::allocator::init_heap();
+    // 注册 CCMRAM 等额外的内存堆区域
+    rtthread_rt::mem::memheap::rt_system_memheap_init();
 
     // hprintln!("Initialization finished.");
 }
@@ -93,8 +95,10 @@ fn init() {
 fn init_thread() {
     // hprintln!("Initializing thread...");
     idle::init_idle();
+    // 创建定时器线程（软定时器）
+    rtthread_rt::timer::rt_system_timer_thread_init();
     // 创建用户主线程
-    let main = thread::rt_thread_create("main", main_entry as usize, rtconfig::RT_MAIN_THREAD_STACK_SIZE as usize, rtconfig::RT_MAIN_THREAD_PRIORITY as u8, 1000);
+    let main = thread::rt_thread_create("main", main_entry as usize, 0, rtconfig::RT_MAIN_THREAD_STACK_SIZE as usize, rtconfig::RT_MAIN_THREAD_PRIORITY as u8, 1000).unwrap();
     main.inner.exclusive_access().stat = ThreadState::Ready;
     thread_priority_table::insert_thread(main.clone());
     // 启动调度器
@@ -0,0 +1,166 @@
+//! IPC 相关的 Result 风格接口
+//!
+//! 函数：sem_create、sem_delete、sem_take、sem_trytake、sem_release、
+//! mutex_create、mutex_delete、mutex_take、mutex_trytake、mutex_release、
+//! event_create、event_delete、event_send、event_recv、
+//! mb_create、mb_delete、mb_send、mb_send_wait、mb_urgent、mb_recv、
+//! mq_create、mq_delete、mq_send、mq_send_wait、mq_urgent、mq_recv
+
+#![warn(unused_imports)]
+
+extern crate alloc;
+use alloc::sync::Arc;
+
+use crate::rtthread_rt::rtdef::*;
+use crate::rtthread_rt::ipc::*;
+
+/// 创建 semaphore
+/// @return Err(RtError::InvalidArgument): 初始计数超过 RT_SEM_VALUE_MAX
+pub fn sem_create(name: &str, count: u32) -> Result<Arc<Semaphore>, RtError> {
+    rt_sem_create(name, count).ok_or(RtError::InvalidArgument)
+}
+
+/// 删除 semaphore
+pub fn sem_delete(sem: Arc<Semaphore>) -> Result<(), RtError> {
+    rt_result(rt_sem_delete(sem))
+}
+
+/// 获取 semaphore
+/// @param timeout 等待时间（tick）：RT_WAITING_NO 不等待，RT_WAITING_FOREVER 永久等待
+/// @return Err(RtError::Timeout): 超时
+pub fn sem_take(sem: Arc<Semaphore>, timeout: i32) -> Result<(), RtError> {
+    rt_result(rt_sem_take(sem, timeout))
+}
+
+/// 尝试获取 semaphore，不等待
+pub fn sem_trytake(sem: Arc<Semaphore>) -> Result<(), RtError> {
+    rt_result(rt_sem_trytake(sem))
+}
+
+/// 释放 semaphore
+pub fn sem_release(sem: Arc<Semaphore>) -> Result<(), RtError> {
+    rt_result(rt_sem_release(sem))
+}
+
+/// 创建 mutex
+pub fn mutex_create(name: &str) -> Result<Arc<RtMutex>, RtError> {
+    Ok(rt_mutex_create(name))
+}
+
+/// 删除 mutex
+pub fn mutex_delete(mutex: Arc<RtMutex>) -> Result<(), RtError> {
+    rt_result(rt_mutex_delete(mutex))
+}
+
+/// 获取 mutex
+/// @param timeout 等待时间（tick）
+pub fn mutex_take(mutex: Arc<RtMutex>, timeout: i32) -> Result<(), RtError> {
+    rt_result(rt_mutex_take(mutex, timeout))
+}
+
+/// 尝试获取 mutex，不等待
+pub fn mutex_trytake(mutex: Arc<RtMutex>) -> Result<(), RtError> {
+    rt_result(rt_mutex_trytake(mutex))
+}
+
+/// 释放 mutex
+pub fn mutex_release(mutex: Arc<RtMutex>) -> Result<(), RtError> {
+    rt_result(rt_mutex_release(mutex))
+}
+
+/// 创建 event
+pub fn event_create(name: &str) -> Result<Arc<RtEvent>, RtError> {
+    Ok(rt_event_create(name))
+}
+
+/// 删除 event
+pub fn event_delete(event: Arc<RtEvent>) -> Result<(), RtError> {
+    rt_result(rt_event_delete(event))
+}
+
+/// 发送事件
+pub fn event_send(event: Arc<RtEvent>, set: u32) -> Result<(), RtError> {
+    rt_result(rt_event_send(event, set))
+}
+
+/// 接收事件
+/// @param option RT_EVENT_FLAG_AND / RT_EVENT_FLAG_OR，可与 RT_EVENT_FLAG_CLEAR 组合
+/// @param timeout 等待时间（tick）
+/// @return Ok: 收到的事件
+pub fn event_recv(event: Arc<RtEvent>, set: u32, option: u8, timeout: i32) -> Result<u32, RtError> {
+    let mut recved = 0;
+    rt_result(rt_event_recv(event, set, option, timeout, &mut recved))?;
+    Ok(recved)
+}
+
+/// 创建 mailbox
+/// @return Err(RtError::InvalidArgument): 容量为0或超过 RT_MB_ENTRY_MAX
+pub fn mb_create(name: &str, size: usize) -> Result<Arc<RtMailbox>, RtError> {
+    rt_mb_create(name, size).ok_or(RtError::InvalidArgument)
+}
+
+/// 删除 mailbox
+pub fn mb_delete(mb: Arc<RtMailbox>) -> Result<(), RtError> {
+    rt_result(rt_mb_delete(mb))
+}
+
+/// 发送邮件，邮箱满时立即返回 Err(RtError::Full)
+pub fn mb_send(mb: Arc<RtMailbox>, value: usize) -> Result<(), RtError> {
+    rt_result(rt_mb_send(mb, value))
+}
+
+/// 发送邮件，邮箱满时等待
+/// @param timeout 等待时间（tick）
+pub fn mb_send_wait(mb: Arc<RtMailbox>, value: usize, timeout: i32) -> Result<(), RtError> {
+    rt_result(rt_mb_send_wait(mb, value, timeout))
+}
+
+/// 发送紧急邮件
+pub fn mb_urgent(mb: Arc<RtMailbox>, value: usize) -> Result<(), RtError> {
+    rt_result(rt_mb_urgent(mb, value))
+}
+
+/// 接收邮件
+/// @param timeout 等待时间（tick）
+/// @return Ok: 收到的邮件
+pub fn mb_recv(mb: Arc<RtMailbox>, timeout: i32) -> Result<usize, RtError> {
+    let mut value = 0;
+    rt_result(rt_mb_recv(mb, &mut value, timeout))?;
+    Ok(value)
+}
+
+/// 创建 message queue
+/// @return Err(RtError::InvalidArgument): 参数不合法
+pub fn mq_create(name: &str, msg_size: usize, max_msgs: usize, flag: u8) -> Result<Arc<RtMessageQueue>, RtError> {
+    rt_mq_create(name, msg_size, max_msgs, flag).ok_or(RtError::InvalidArgument)
+}
+
+/// 删除 message queue
+pub fn mq_delete(mq: Arc<RtMessageQueue>) -> Result<(), RtError> {
+    rt_result(rt_mq_delete(mq))
+}
+
+/// 发送消息，队列满时立即返回 Err(RtError::Full)
+pub fn mq_send(mq: Arc<RtMessageQueue>, buffer: &[u8]) -> Result<(), RtError> {
+    rt_result(rt_mq_send(mq, buffer))
+}
+
+/// 发送消息，队列满时等待
+/// @param timeout 等待时间（tick）
+pub fn mq_send_wait(mq: Arc<RtMessageQueue>, buffer: &[u8], timeout: i32) -> Result<(), RtError> {
+    rt_result(rt_mq_send_wait(mq, buffer, timeout))
+}
+
+/// 发送紧急消息
+pub fn mq_urgent(mq: Arc<RtMessageQueue>, buffer: &[u8]) -> Result<(), RtError> {
+    rt_result(rt_mq_urgent(mq, buffer))
+}
+
+/// 接收消息
+/// @param timeout 等待时间（tick）
+/// @return Ok: 消息长度
+pub fn mq_recv(mq: Arc<RtMessageQueue>, buffer: &mut [u8], timeout: i32) -> Result<usize, RtError> {
+    let mut recv_len = 0;
+    rt_result(rt_mq_recv(mq, buffer, timeout, &mut recv_len))?;
+    Ok(recv_len)
+}
@@ -0,0 +1,106 @@
+//! 内存管理相关的 Result 风格接口
+//!
+//! 分配失败时返回 Err 而不是空指针，成功时返回 NonNull
+//!
+//! 函数：smem_init、smem_alloc、smem_realloc、smem_free、memheap_init、memheap_alloc、memheap_alloc_tag、memheap_free、
+//!       mp_create、mp_delete、mp_alloc、mp_free
+
+#![warn(unused_imports)]
+
+extern crate alloc;
+use alloc::sync::Arc;
+use core::ptr::NonNull;
+
+use crate::rtthread_rt::rtdef::*;
+use crate::rtthread_rt::mem::small_mem_impl::RTSmemT;
+use crate::rtthread_rt::mem::*;
+use crate::rtthread_rt::thread::rt_thread_self;
+
+/// 在一块内存区域上初始化小内存管理器
+/// @return Err(RtError::InvalidArgument): 区域太小
+pub fn smem_init(name: &str, begin_addr: *mut u8, size: usize) -> Result<RTSmemT, RtError> {
+    let m = rt_smem_init(name, begin_addr, size);
+    if m.is_null() {
+        return Err(RtError::InvalidArgument);
+    }
+    Ok(m)
+}
+
+/// 从小内存管理器分配内存
+/// @return Err(RtError::NoMemory): 内存不足
+pub fn smem_alloc(m: RTSmemT, size: usize) -> Result<NonNull<u8>, RtError> {
+    NonNull::new(rt_smem_alloc(m, size)).ok_or(RtError::NoMemory)
+}
+
+/// 调整由 smem_alloc 分配的内存块的大小
+/// 失败时原内存块保持不变，仍需由调用者释放
+/// @return Err(RtError::InvalidArgument): 新大小为0（释放请使用 smem_free）
+///         Err(RtError::NoMemory): 内存不足，或内存块不属于该内存管理器
+pub fn smem_realloc(m: RTSmemT, block: NonNull<u8>, newsize: usize) -> Result<NonNull<u8>, RtError> {
+    if newsize == 0 {
+        return Err(RtError::InvalidArgument);
+    }
+    NonNull::new(rt_smem_realloc(m, block.as_ptr(), newsize)).ok_or(RtError::NoMemory)
+}
+
+/// 释放由 smem_alloc / smem_realloc 分配的内存块
+pub fn smem_free(block: NonNull<u8>) {
+    rt_smem_free(block.as_ptr());
+}
+
+/// 注册内存堆区域，见 rt_memheap_init
+pub fn memheap_init(name: &str, tag: u8, start: *mut u8, size: usize) -> Result<(), RtError> {
+    rt_result(rt_memheap_init(name, tag, start, size))
+}
+
+/// 从内存堆分配内存
+/// @return Err(RtError::NoMemory): 所有区域都无法满足
+pub fn memheap_alloc(size: usize) -> Result<NonNull<u8>, RtError> {
+    NonNull::new(rt_memheap_alloc(size)).ok_or(RtError::NoMemory)
+}
+
+/// 从指定标签的内存堆区域分配内存
+/// @return Err(RtError::NoMemory): 该标签的区域都无法满足
+pub fn memheap_alloc_tag(tag: u8, size: usize) -> Result<NonNull<u8>, RtError> {
+    NonNull::new(rt_memheap_alloc_tag(tag, size)).ok_or(RtError::NoMemory)
+}
+
+/// 释放由 memheap_alloc / memheap_alloc_tag 分配的内存块
+pub fn memheap_free(block: NonNull<u8>) {
+    rt_memheap_free(block.as_ptr());
+}
+
+/// 创建内存池
+/// @return Err(RtError::InvalidArgument): 区域不足以容纳一个内存块
+pub fn mp_create(name: &str, start: *mut u8, size: usize, block_size: usize) -> Result<Arc<RtMemPool>, RtError> {
+    rt_mp_create(name, start, size, block_size).ok_or(RtError::InvalidArgument)
+}
+
+/// 删除内存池
+pub fn mp_delete(mp: Arc<RtMemPool>) -> Result<(), RtError> {
+    rt_result(rt_mp_delete(mp))
+}
+
+/// 从内存池分配一个内存块
+/// @param timeout 等待时间（tick）：RT_WAITING_NO 不等待，小于0表示永久等待
+/// @return Err(RtError::Empty): 不等待且没有空闲块
+///         Err(RtError::Timeout): 等待超时
+pub fn mp_alloc(mp: Arc<RtMemPool>, timeout: i32) -> Result<NonNull<u8>, RtError> {
+    if let Some(block) = NonNull::new(rt_mp_alloc(mp, timeout)) {
+        return Ok(block);
+    }
+    if timeout == RT_WAITING_NO {
+        return Err(RtError::Empty);
+    }
+    // 等待失败时，错误码记录在当前线程上
+    let error = rt_thread_self().map_or(RT_ETIMEOUT, |thread| thread.inner.exclusive_access().error);
+    match RtError::from_code(error) {
+        RtError::Ok => Err(RtError::Timeout),
+        error => Err(error),
+    }
+}
+
+/// 将内存块归还内存池
+pub fn mp_free(block: NonNull<u8>) {
+    rt_mp_free(block.as_ptr());
+}
@@ -0,0 +1,24 @@
+//! Result 风格的内核接口
+//!
+//! 对线程、IPC、定时器和内存管理的 C 风格接口（返回 RtErrT 或空指针）进行包装，
+//! 统一返回 `Result<T, RtError>`，以便在应用线程中使用 `?` 传播错误。
+//! 错误码与 RtError 之间的转换见 rtdef 中的 RtError::code / RtError::from_code。
+//!
+//! 使用示例：
+//! ```rust
+//! fn worker() -> Result<(), RtError> {
+//!     api::ipc::sem_take(sem.clone(), 100)?;
+//!     let value = api::ipc::mb_recv(mb.clone(), RT_WAITING_FOREVER)?;
+//!     api::thread::sleep(10)?;
+//!     Ok(())
+//! }
+//! ```
+
+#![warn(unused_imports)]
+
+pub mod thread;
+pub mod ipc;
+pub mod timer;
+pub mod mem;
+
+pub use crate::rtthread_rt::rtdef::{RtError, rt_result, rt_result_code};
@@ -0,0 +1,109 @@
+//! 线程相关的 Result 风格接口
+//!
+//! 函数：current、create、init、spawn、startup、delete、suspend、resume、sleep、delay、mdelay、delay_until、yield_now、set_priority、control、kill、signal_wait
+
+#![warn(unused_imports)]
+
+extern crate alloc;
+use alloc::sync::Arc;
+
+use crate::rtthread_rt::rtdef::*;
+use crate::rtthread_rt::thread::*;
+
+/// 获取当前线程
+/// @return Err(RtError::Error): 调度器尚未启动
+pub fn current() -> Result<Arc<RtThread>, RtError> {
+    rt_thread_self().ok_or(RtError::Error)
+}
+
+/// 创建线程，见 rt_thread_create
+pub fn create(name: &str, entry: usize, parameter: usize, stack_size: usize, priority: u8, tick: usize) -> Result<Arc<RtThread>, RtError> {
+    rt_thread_create(name, entry, parameter, stack_size, priority, tick)
+}
+
+/// 初始化静态线程，见 rt_thread_init
+pub fn init(storage: &'static mut RtThreadStorage, name: &str, entry: usize, parameter: usize, stack: &'static mut [u8], priority: u8, tick: usize) -> Result<Arc<RtThread>, RtError> {
+    rt_thread_init(storage, name, entry, parameter, stack, priority, tick)
+}
+
+/// 以闭包创建并启动线程，见 rt_thread_spawn
+pub fn spawn<F>(name: &str, stack_size: usize, priority: u8, tick: usize, f: F) -> Result<Arc<RtThread>, RtError>
+where
+    F: FnOnce() + Send + 'static,
+{
+    rt_thread_spawn(name, stack_size, priority, tick, f)
+}
+
+/// 启动线程
+pub fn startup(thread: Arc<RtThread>) -> Result<(), RtError> {
+    rt_result(rt_thread_startup(thread))
+}
+
+/// 删除线程
+pub fn delete(thread: Arc<RtThread>) -> Result<(), RtError> {
+    rt_result(rt_thread_delete(thread))
+}
+
+/// 挂起线程
+pub fn suspend(thread: Arc<RtThread>) -> Result<(), RtError> {
+    rt_result(rt_thread_suspend(thread))
+}
+
+/// 恢复线程
+pub fn resume(thread: Arc<RtThread>) -> Result<(), RtError> {
+    rt_result(rt_thread_resume(thread))
+}
+
+/// 当前线程睡眠
+/// @param tick 睡眠时间（tick）
+pub fn sleep(tick: usize) -> Result<(), RtError> {
+    rt_result(rt_thread_sleep(current()?, tick))
+}
+
+/// 当前线程延时
+/// @param tick 延时时间（tick），为0时仅让出CPU
+/// @return Err(RtError::Interrupted): 被提前唤醒
+pub fn delay(tick: u32) -> Result<(), RtError> {
+    rt_result(rt_thread_delay(tick))
+}
+
+/// 当前线程延时（毫秒）
+/// @return Err(RtError::Interrupted): 被提前唤醒
+pub fn mdelay(ms: u32) -> Result<(), RtError> {
+    rt_result(rt_thread_mdelay(ms))
+}
+
+/// 当前线程睡眠到 *last_wake + period，见 rt_thread_delay_until
+pub fn delay_until(last_wake: &mut u32, period: u32) -> Result<(), RtError> {
+    rt_result(rt_thread_delay_until(last_wake, period))
+}
+
+/// 当前线程让出CPU
+pub fn yield_now() -> Result<(), RtError> {
+    rt_result(rt_thread_yield())
+}
+
+/// 设置线程优先级
+pub fn set_priority(thread: Arc<RtThread>, priority: u8) -> Result<(), RtError> {
+    rt_result(rt_thread_set_priority(thread, priority))
+}
+
+/// 控制线程，见 rt_thread_control
+pub fn control(thread: Arc<RtThread>, cmd: u8, arg: u8) -> Result<(), RtError> {
+    rt_result(rt_thread_control(thread, cmd, arg))
+}
+
+/// 向线程发送信号
+pub fn kill(thread: Arc<RtThread>, sig: i32) -> Result<(), RtError> {
+    rt_result(rt_thread_kill(thread, sig))
+}
+
+/// 等待信号
+/// @param set 等待的信号集合
+/// @param timeout 等待时间（tick）
+/// @return Ok: 收到的信号信息
+pub fn signal_wait(set: u32, timeout: i32) -> Result<RtSigInfo, RtError> {
+    let mut info = RtSigInfo::default();
+    rt_result(rt_signal_wait(set, &mut info, timeout))?;
+    Ok(info)
+}
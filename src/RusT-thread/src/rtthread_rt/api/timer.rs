@@ -0,0 +1,66 @@
+//! 定时器相关的 Result 风格接口
+//!
+//! 函数：create、start、stop、restart、remain_time
+
+#![warn(unused_imports)]
+
+extern crate alloc;
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+use spin::Mutex;
+
+use crate::rtthread_rt::rtdef::*;
+use crate::rtthread_rt::timer::*;
+
+/// 创建定时器（未启动）
+/// @param name 名称
+/// @param flag 定时器标志，见 RtTimer::new
+/// @param tick 超时时间（tick）
+/// @param callback 超时回调
+/// @return Err(RtError::InvalidArgument): 超时时间为0
+pub fn create<F>(name: &str, flag: u8, tick: u32, callback: F) -> Result<TimerHandle, RtError>
+where
+    F: FnMut() + Send + Sync + 'static,
+{
+    if tick == 0 {
+        return Err(RtError::InvalidArgument);
+    }
+    Ok(Arc::new(Mutex::new(RtTimer::new(name, 0, flag, Some(Box::new(callback)), tick, tick))))
+}
+
+/// 启动定时器
+pub fn start(timer: TimerHandle) -> Result<(), RtError> {
+    rt_timer_start(timer);
+    Ok(())
+}
+
+/// 停止定时器
+/// @return Err(RtError::Error): 定时器未启动
+pub fn stop(timer: &TimerHandle) -> Result<(), RtError> {
+    let mut state = 0;
+    rt_timer_control(timer, TimerControlCmd::GetState(&mut state));
+    if state & RT_TIMER_FLAG_ACTIVATED as u32 == 0 {
+        return Err(RtError::Error);
+    }
+    rt_timer_stop(timer);
+    Ok(())
+}
+
+/// 从当前时刻起重新计时，未启动的定时器会被启动
+pub fn restart(timer: &TimerHandle) -> Result<(), RtError> {
+    rt_timer_control(timer, TimerControlCmd::Restart);
+    Ok(())
+}
+
+/// 获取距离超时的剩余时间（tick）
+/// @return Err(RtError::Error): 定时器未启动
+pub fn remain_time(timer: &TimerHandle) -> Result<u32, RtError> {
+    let mut state = 0;
+    rt_timer_control(timer, TimerControlCmd::GetState(&mut state));
+    if state & RT_TIMER_FLAG_ACTIVATED as u32 == 0 {
+        return Err(RtError::Error);
+    }
+    let mut remain = 0;
+    rt_timer_control(timer, TimerControlCmd::GetRemainTime(&mut remain));
+    Ok(remain)
+}
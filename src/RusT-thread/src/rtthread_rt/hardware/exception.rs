@@ -13,6 +13,7 @@ use cortex_m_semihosting::hprintln;
 use core::fmt;
 use core::fmt::Debug;
 use crate::rtthread_rt::hardware::cpuport::StackFrame;
+use crate::rtthread_rt::thread::{rt_stack_overflow_thread, list_thread};
 
 // 异常钩子
 static mut RT_EXCEPTION_HOOK: Option<unsafe fn(context: *mut core::ffi::c_void) -> i32> = None;
@@ -43,6 +44,7 @@ const SCB_CFSR_UFSR: *const u16 = 0xE000ED2A as *const u16;
 #[exception]
 fn MemoryManagement() -> ! {
     unsafe {
+        stack_overflow_track();
         mem_manage_fault_track();
     }
     loop {}
@@ -143,6 +145,22 @@ unsafe fn mem_manage_fault_track() {
     }
 }
 
+// 栈溢出跟踪：由栈检查或 MPU 栈保护区触发时打印溢出的线程
+unsafe fn stack_overflow_track() {
+    let mfsr = core::ptr::read_volatile(SCB_CFSR_MFSR);
+    let thread = if mfsr & (1 << 7) != 0 {
+        rt_stack_overflow_thread(Some(core::ptr::read_volatile(SCB_MMAR) as usize))
+    } else if mfsr & (1 << 4) != 0 || mfsr == 0 {
+        // 异常入栈出错或由栈检查挂起的异常，地址未知
+        rt_stack_overflow_thread(None)
+    } else {
+        None
+    };
+    if let Some(thread) = thread {
+        let _ = hprintln!("thread: {} stack overflow", thread.thread_name());
+    }
+}
+
 // hard fault 跟踪
 unsafe fn hard_fault_track() {
     let hfsr = core::ptr::read_volatile(SCB_HFSR);
@@ -191,7 +209,7 @@ pub unsafe fn rt_hw_hard_fault_exception(exception_info: *mut ExceptionInfo) {
     // 线程名等可根据实际RTOS API补充
     if ((*exception_info).exc_return & (1 << 2)) != 0 {
         kprintf!("hard fault on thread: <thread_name_placeholder>\n\n");
-        // list_thread(); // 需要RTOS支持，可补充
+        list_thread();
     } else {
         kprintf!("hard fault on handler\n\n");
     }
@@ -1,14 +1,16 @@
 //! 硬件相关函数-Cortex-M4
 //! 
-//! 定义了线程上下文切换、异常处理、中断处理、CPU相关函数
+//! 定义了线程上下文切换、异常处理、中断处理、CPU、MPU相关函数
 
 
 pub mod context;
 pub mod cpuport;
 pub mod irq;
 pub mod exception;
+pub mod mpu;
 
 pub use self::cpuport::{rt_hw_stack_init, rt_hw_cpu_shutdown, rt_hw_cpu_reset, ExceptionStackFrame, StackFrame};
 pub use self::irq::{rt_hw_interrupt_disable, rt_hw_interrupt_enable, rt_interrupt_enter, rt_interrupt_leave, rt_interrupt_get_nest, rt_hw_get_interrupt_level};
 pub use self::exception::{rt_hw_exception_install, ExceptionInfo};
+pub use self::mpu::{rt_hw_mpu_init, rt_hw_stack_guard_set, rt_hw_mem_fault_pend};
 pub use self::context::{init, rt_hw_context_switch, rt_hw_context_switch_interrupt, rt_hw_context_switch_to};
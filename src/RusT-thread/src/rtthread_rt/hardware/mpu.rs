@@ -0,0 +1,75 @@
+//! MPU相关函数-Cortex-M4
+//!
+//! 使用一个 MPU 区域作为当前运行线程的栈保护区（栈底的一小段），
+//! 线程栈溢出写入保护区时触发 MemoryManagement 异常
+//!
+//! 函数：rt_hw_mpu_init、rt_hw_stack_guard_set、rt_hw_mem_fault_pend
+
+#![warn(unused_imports)]
+
+use core::arch::asm;
+
+// 寄存器地址常量
+const SCB_SHCSR: *mut u32 = 0xE000ED24 as *mut u32;
+const MPU_TYPE: *const u32 = 0xE000ED90 as *const u32;
+const MPU_CTRL: *mut u32 = 0xE000ED94 as *mut u32;
+const MPU_RBAR: *mut u32 = 0xE000ED9C as *mut u32;
+const MPU_RASR: *mut u32 = 0xE000EDA0 as *mut u32;
+
+const SCB_SHCSR_MEMFAULTENA: u32 = 1 << 16;
+const SCB_SHCSR_MEMFAULTPENDED: u32 = 1 << 13;
+const MPU_CTRL_ENABLE: u32 = 1 << 0;
+const MPU_CTRL_PRIVDEFENA: u32 = 1 << 2;
+const MPU_RBAR_VALID: u32 = 1 << 4;
+const MPU_RASR_ENABLE: u32 = 1 << 0;
+const MPU_RASR_XN: u32 = 1 << 28;
+/// 特权只读、非特权不可访问
+const MPU_RASR_AP_PRIV_RO: u32 = 0b101 << 24;
+/// 普通内存，可共享、可缓存（内部 SRAM）
+const MPU_RASR_SRAM: u32 = (1 << 18) | (1 << 17);
+
+/// 栈保护区使用的 MPU 区域号
+const MPU_STACK_GUARD_REGION: u32 = 0;
+
+/// 初始化 MPU 栈保护
+/// 使能 MemoryManagement 异常；若 `enable_mpu` 为 true 且芯片带有 MPU，
+/// 则使能 MPU（未被区域覆盖的地址仍使用默认内存映射）
+/// @param enable_mpu 是否使能 MPU
+/// @return true: MPU 已使能
+///         false: 未使能 MPU
+pub fn rt_hw_mpu_init(enable_mpu: bool) -> bool {
+    unsafe {
+        let shcsr = core::ptr::read_volatile(SCB_SHCSR);
+        core::ptr::write_volatile(SCB_SHCSR, shcsr | SCB_SHCSR_MEMFAULTENA);
+
+        // MPU_TYPE.DREGION 为 0 表示没有 MPU
+        if !enable_mpu || (core::ptr::read_volatile(MPU_TYPE) >> 8) & 0xff == 0 {
+            return false;
+        }
+        core::ptr::write_volatile(MPU_CTRL, MPU_CTRL_ENABLE | MPU_CTRL_PRIVDEFENA);
+        asm!("dsb", "isb");
+    }
+    true
+}
+
+/// 设置栈保护区
+/// @param addr 保护区起始地址，需按 size 对齐
+/// @param size 保护区大小，需为 2 的幂且不小于 32
+pub fn rt_hw_stack_guard_set(addr: usize, size: usize) {
+    // 区域大小为 2^(SIZE+1)
+    let size_field = size.trailing_zeros() - 1;
+    unsafe {
+        core::ptr::write_volatile(MPU_RBAR, addr as u32 | MPU_RBAR_VALID | MPU_STACK_GUARD_REGION);
+        core::ptr::write_volatile(MPU_RASR, MPU_RASR_XN | MPU_RASR_AP_PRIV_RO | MPU_RASR_SRAM | (size_field << 1) | MPU_RASR_ENABLE);
+        asm!("dsb", "isb");
+    }
+}
+
+/// 挂起 MemoryManagement 异常，开中断后立即进入异常处理
+pub fn rt_hw_mem_fault_pend() {
+    unsafe {
+        let shcsr = core::ptr::read_volatile(SCB_SHCSR);
+        core::ptr::write_volatile(SCB_SHCSR, shcsr | SCB_SHCSR_MEMFAULTPENDED);
+        asm!("dsb", "isb");
+    }
+}
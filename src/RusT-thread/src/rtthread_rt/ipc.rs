@@ -1,19 +1,48 @@
 //! ipc 模块
 //! 
-//! 本模块为RusT-Thread的IPC机制，目前实现了信号量的操作
+//! 本模块为RusT-Thread的IPC机制，目前实现了信号量、互斥量、事件集、邮箱、消息队列的操作
 //! 
 //! 结构体：
 //!     IPCBase: 基础 IPC 结构体
 //!     Semaphore: 信号量结构体
+//!     RtMutex: 互斥量结构体（支持递归持有与优先级继承）
+//!     RtEvent: 事件集结构体
+//!     RtMailbox: 邮箱结构体
+//!     RtMessageQueue: 消息队列结构体
 //! 函数：
 //!     _ipc_init: 初始化 IPC 结构体
-//!     _ipc_list_suspend: 将线程挂起，并按优先级插入线程队列
+//!     _ipc_list_suspend: 将线程挂起，并按 FIFO 或优先级插入线程队列
 //!     _ipc_list_resume: 将线程唤醒
 //!     _ipc_list_resume_all: 将所有线程唤醒
+//!     _ipc_list_resume_all_error: 以指定错误码唤醒所有线程
+//!     _ipc_wait: 将当前线程挂起在 IPC 上等待（可超时）
+//!     _ipc_remain_timeout: 等待被打断后计算剩余等待时间
 //!     rt_sem_create: 创建并初始化 semaphore 结构体
 //!     rt_sem_delete: 删除 semaphore 结构体
-//!     rt_sem_take: 获取 semaphore
+//!     rt_sem_take: 获取 semaphore（支持超时）
+//!     rt_sem_trytake: 尝试获取 semaphore
 //!     rt_sem_release: 释放 semaphore
+//!     rt_mutex_create: 创建 mutex
+//!     rt_mutex_delete: 删除 mutex
+//!     rt_mutex_take: 获取 mutex
+//!     rt_mutex_trytake: 尝试获取 mutex（不等待）
+//!     rt_mutex_release: 释放 mutex
+//!     rt_event_create: 创建 event
+//!     rt_event_delete: 删除 event
+//!     rt_event_send: 发送事件
+//!     rt_event_recv: 接收事件
+//!     rt_mb_create: 创建 mailbox
+//!     rt_mb_delete: 删除 mailbox
+//!     rt_mb_send: 发送邮件（不等待）
+//!     rt_mb_send_wait: 发送邮件（邮箱满时等待）
+//!     rt_mb_urgent: 发送紧急邮件
+//!     rt_mb_recv: 接收邮件
+//!     rt_mq_create: 创建 message queue
+//!     rt_mq_delete: 删除 message queue
+//!     rt_mq_send: 发送消息（不等待）
+//!     rt_mq_send_wait: 发送消息（队列满时等待）
+//!     rt_mq_urgent: 发送紧急消息
+//!     rt_mq_recv: 接收消息
 
 use lazy_static::lazy_static;
 
@@ -25,7 +54,9 @@ use alloc::boxed::Box;
 use crate::rtthread_rt::rtdef::*;
 use crate::rtthread_rt::kservice::RTIntrFreeCell;
 use crate::rtthread_rt::rtconfig::*;
+use crate::rtthread_rt::rtconfig;
 use crate::rtthread_rt::thread::*;
+use crate::rtthread_rt::thread::thread::_thread_timeout_start;
 use crate::rtthread_rt::hardware::*;
 use crate::rtthread_rt::timer::*;
 
@@ -44,10 +75,16 @@ pub struct IPCBase {
     pub name: [u8; RT_NAME_MAX],
     pub object_type: u8,
 
+    /// 等待队列排序方式：RT_IPC_FLAG_FIFO 或 RT_IPC_FLAG_PRIO
+    pub flag: u8,
+
     /// 线程队列
     pub thread_queue: RTIntrFreeCell<Vec<Arc<RtThread>>>,
 }
 
+/// 信号量对象类型
+pub const RT_OBJECT_CLASS_SEMAPHORE: u8 = 2;
+
 /// semaphore 结构体
 pub struct Semaphore {
     /// 基础 IPC 结构体
@@ -57,11 +94,126 @@ pub struct Semaphore {
     pub count: Mutex<u32>,
 }
 
+/// 互斥量对象类型
+pub const RT_OBJECT_CLASS_MUTEX: u8 = 3;
+
+/// mutex 结构体
+pub struct RtMutex {
+    /// 基础 IPC 结构体
+    pub parent: RTIntrFreeCell<Arc<IPCBase>>,
+
+    /// 互斥量状态
+    pub inner: RTIntrFreeCell<RtMutexInner>,
+}
+
+/// 事件集对象类型
+pub const RT_OBJECT_CLASS_EVENT: u8 = 4;
+
+/// event 结构体
+pub struct RtEvent {
+    /// 基础 IPC 结构体
+    pub parent: RTIntrFreeCell<Arc<IPCBase>>,
+
+    /// 事件集合，每一位代表一个事件
+    pub set: Mutex<u32>,
+}
+
+/// 邮箱对象类型
+pub const RT_OBJECT_CLASS_MAILBOX: u8 = 5;
+
+/// mailbox 结构体
+pub struct RtMailbox {
+    /// 基础 IPC 结构体（接收者等待队列）
+    pub parent: RTIntrFreeCell<Arc<IPCBase>>,
+
+    /// 发送者等待队列（邮箱满时挂起的发送线程）
+    pub suspend_sender: RTIntrFreeCell<Arc<IPCBase>>,
+
+    /// 邮箱状态
+    pub inner: RTIntrFreeCell<RtMailboxInner>,
+}
+
+/// mailbox 的可变状态（环形缓冲区）
+pub struct RtMailboxInner {
+    /// 邮件缓冲区，每封邮件为一个 usize
+    pub msg_pool: Vec<usize>,
+    /// 邮箱容量
+    pub size: u16,
+    /// 当前邮件数量
+    pub entry: u16,
+    /// 写入位置
+    pub in_offset: u16,
+    /// 读取位置
+    pub out_offset: u16,
+}
+
+/// 消息队列对象类型
+pub const RT_OBJECT_CLASS_MESSAGEQUEUE: u8 = 6;
+
+/// message queue 结构体
+pub struct RtMessageQueue {
+    /// 基础 IPC 结构体（接收者等待队列）
+    pub parent: RTIntrFreeCell<Arc<IPCBase>>,
+
+    /// 发送者等待队列（队列满时挂起的发送线程）
+    pub suspend_sender: RTIntrFreeCell<Arc<IPCBase>>,
+
+    /// 消息队列状态
+    pub inner: RTIntrFreeCell<RtMessageQueueInner>,
+}
+
+/// message queue 的可变状态
+/// 消息池在创建时一次性分配，收发消息时只做拷贝，不再分配堆内存
+pub struct RtMessageQueueInner {
+    /// 消息池，共 max_msgs 个槽，每个槽 msg_size 字节
+    pub msg_pool: Vec<u8>,
+    /// 每个槽中消息的实际长度
+    pub msg_len: Vec<usize>,
+    /// 单条消息的最大长度
+    pub msg_size: usize,
+    /// 最大消息数量
+    pub max_msgs: u16,
+    /// 当前消息数量
+    pub entry: u16,
+    /// 队首（下一条被接收的消息）所在槽
+    pub head: u16,
+    /// 队尾（下一条消息写入的槽）
+    pub tail: u16,
+}
+
+impl RtMessageQueueInner {
+    /// 将消息拷贝到指定槽
+    fn write_slot(&mut self, slot: u16, buffer: &[u8]) {
+        let start = slot as usize * self.msg_size;
+        self.msg_pool[start..start + buffer.len()].copy_from_slice(buffer);
+        self.msg_len[slot as usize] = buffer.len();
+    }
+
+    /// 从指定槽拷贝消息，返回拷贝的字节数
+    fn read_slot(&self, slot: u16, buffer: &mut [u8]) -> usize {
+        let start = slot as usize * self.msg_size;
+        let len = self.msg_len[slot as usize].min(buffer.len());
+        buffer[..len].copy_from_slice(&self.msg_pool[start..start + len]);
+        len
+    }
+}
+
+/// mutex 的可变状态
+pub struct RtMutexInner {
+    /// 持有者线程
+    pub owner: Option<Arc<RtThread>>,
+    /// 持有者获取互斥量之前的优先级，释放时恢复
+    pub original_priority: u8,
+    /// 持有者的递归持有次数
+    pub hold: u8,
+}
+
 /// 初始化 IPC 结构体
 /// @param name 名称
 /// @param object_type 对象类型
+/// @param flag 等待队列排序方式：RT_IPC_FLAG_FIFO 或 RT_IPC_FLAG_PRIO
 /// @return IPC 结构体
-pub fn _ipc_init(name: &str, object_type: u8) -> Arc<IPCBase> {
+pub fn _ipc_init(name: &str, object_type: u8, flag: u8) -> Arc<IPCBase> {
     let name_bytes = name.as_bytes();
     let len = name_bytes.len().min(RT_NAME_MAX);
     let mut name_array = [0u8; RT_NAME_MAX];
@@ -69,18 +221,20 @@ pub fn _ipc_init(name: &str, object_type: u8) -> Arc<IPCBase> {
     Arc::new(IPCBase {
         name: name_array,
         object_type,
+        flag,
         thread_queue: unsafe { RTIntrFreeCell::new(Vec::new()) },
     })
 }
 
-/// 将线程挂起，并按优先级插入线程队列
+/// 将线程挂起，并插入线程队列
+/// RT_IPC_FLAG_PRIO 时按优先级插入，RT_IPC_FLAG_FIFO 时插入队列末尾
 /// @param ipc IPC 结构体
 /// @param thread 线程
 pub fn _ipc_list_suspend(ipc: Arc<IPCBase>, thread: Arc<RtThread>) {
     rt_thread_suspend(thread.clone());
     
-    // 若队列为空，则直接插入队列
-    if ipc.thread_queue.exclusive_session(|queue| queue.is_empty()) {
+    // 若队列为空或按先进先出排序，则直接插入队列末尾
+    if ipc.flag == RT_IPC_FLAG_FIFO || ipc.thread_queue.exclusive_session(|queue| queue.is_empty()) {
         // hprintln!("ipc.thread_queue is empty");
         ipc.thread_queue.exclusive_session(|queue| {
             queue.push(thread);
@@ -91,7 +245,7 @@ pub fn _ipc_list_suspend(ipc: Arc<IPCBase>, thread: Arc<RtThread>) {
         ipc.thread_queue.exclusive_session(|queue| {
             for i in 0..queue.len() {
                 // 按优先级插入队列
-                if queue[i].inner.field_ptr(|thread| &thread.current_priority) > thread.inner.field_ptr(|thread| &thread.current_priority) {
+                if queue[i].inner.exclusive_access().current_priority > thread.inner.exclusive_access().current_priority {
                     queue.insert(i, thread.clone());
                     return;
                 }
@@ -113,6 +267,7 @@ pub fn _ipc_list_resume(ipc: Arc<IPCBase>) -> Option<Arc<RtThread>> {
             None
         } else {
             let thread = queue.remove(0);
+            _ipc_stop_timeout(&thread);
             rt_thread_resume(thread.clone());
             Some(thread)
         }
@@ -127,6 +282,7 @@ pub fn _ipc_list_resume_all(ipc: Arc<IPCBase>) {
     ipc.thread_queue.exclusive_session(|queue| {
         // 唤醒所有线程
         for thread in queue.iter() {
+            _ipc_stop_timeout(thread);
             rt_thread_resume(thread.clone());
         }
         // 清空队列
@@ -135,85 +291,128 @@ pub fn _ipc_list_resume_all(ipc: Arc<IPCBase>) {
     rt_hw_interrupt_enable(level);
 }
 
+/// 以指定错误码唤醒所有线程（用于删除 IPC 对象）
+/// @param ipc IPC 结构体
+/// @param error 被唤醒线程的错误码
+pub fn _ipc_list_resume_all_error(ipc: Arc<IPCBase>, error: RtErrT) {
+    let level = rt_hw_interrupt_disable();
+    ipc.thread_queue.exclusive_session(|queue| {
+        for thread in queue.iter() {
+            thread.inner.exclusive_access().error = error;
+        }
+    });
+    _ipc_list_resume_all(ipc);
+    rt_hw_interrupt_enable(level);
+}
+
+/// 停止线程上挂着的 IPC 超时定时器（线程被正常唤醒时调用）
+fn _ipc_stop_timeout(thread: &Arc<RtThread>) {
+    let timer = thread.inner.exclusive_access().timer.take();
+    if let Some(timer) = timer {
+        rt_timer_stop(&timer);
+    }
+}
+
+/// 将线程挂起在 IPC 上等待，直到被唤醒或超时
+/// 调用前需已关中断，`level` 为关中断时返回的中断状态，本函数负责恢复
+/// @param ipc IPC 结构体
+/// @param thread 线程（当前线程）
+/// @param timeout 等待时间（tick），小于0表示永久等待
+/// @return 线程被唤醒后的错误码：RT_EOK 表示正常唤醒，RT_ETIMEOUT 表示超时，RT_EINTR 表示被信号打断
+pub fn _ipc_wait(ipc: Arc<IPCBase>, thread: Arc<RtThread>, timeout: i32, level: u32) -> RtErrT {
+    thread.inner.exclusive_session(|inner| {
+        inner.error = RT_EOK;
+        inner.suspend_ipc = Some(ipc.clone());
+    });
+    _ipc_list_suspend(ipc.clone(), thread.clone());
+    // 使用线程自身的超时定时器，到期时将线程从等待队列中移除并以 RT_ETIMEOUT 唤醒
+    if timeout > 0 {
+        _thread_timeout_start(&thread, timeout as u32);
+    }
+    rt_hw_interrupt_enable(level);
+    rt_schedule();
+
+    // 线程被信号提前唤醒（RT_EINTR）时仍在等待队列中，需要将其移除；
+    // 超时定时器若仍挂在线程上则立即停止，避免之后对无关的等待触发，或使 rt_thread_sleep 拒绝睡眠
+    let level = rt_hw_interrupt_disable();
+    ipc.thread_queue.exclusive_session(|queue| queue.retain(|t| !Arc::ptr_eq(t, &thread)));
+    let timer = thread.inner.exclusive_session(|inner| {
+        inner.suspend_ipc = None;
+        inner.timer.take()
+    });
+    if let Some(timer) = timer {
+        rt_timer_stop_sync(&timer);
+    }
+    rt_hw_interrupt_enable(level);
+    thread.inner.exclusive_access().error
+}
+
 /// 创建并初始化 semaphore 结构体
 /// @param name 名称
-/// @param count 计数
-/// @return RT_EOK: 初始化成功
-///         RT_ERROR: 初始化失败
-pub fn rt_sem_create(name: &str, count: u32) -> RtErrT {
-    if count > 0x10000u32 {
-        return RT_ERROR;
+/// @param count 初始计数
+/// @return semaphore 结构体，计数超过 RT_SEM_VALUE_MAX 时返回 None
+pub fn rt_sem_create(name: &str, count: u32) -> Option<Arc<Semaphore>> {
+    if count > RT_SEM_VALUE_MAX as u32 {
+        return None;
     }
-    let name_bytes = name.as_bytes();
-    let len = name_bytes.len().min(RT_NAME_MAX);
-    let mut name_array = [0u8; RT_NAME_MAX];
-    name_array[..len].copy_from_slice(&name_bytes[..len]);
-    let ipc_parent = _ipc_init(name, 1);
-    let sem = Arc::new(Semaphore {
+    let ipc_parent = _ipc_init(name, RT_OBJECT_CLASS_SEMAPHORE, RT_IPC_FLAG_PRIO);
+    Some(Arc::new(Semaphore {
         parent: unsafe { RTIntrFreeCell::new(ipc_parent) },
         count: Mutex::new(count),
-    });
-    RT_EOK
+    }))
 }
 
 /// 删除 semaphore 结构体
+/// 唤醒所有等待的线程，其错误码被设置为 RT_ERROR
 /// @param semaphore 结构体
 /// @return RT_EOK: 删除成功
 pub fn rt_sem_delete(sem: Arc<Semaphore>) -> RtErrT {
-    _ipc_list_resume_all(sem.parent.exclusive_session(|ipc| ipc.clone()));
+    let level = rt_hw_interrupt_disable();
+    _ipc_list_resume_all_error(sem.parent.exclusive_session(|ipc| ipc.clone()), RT_ERROR);
+    rt_hw_interrupt_enable(level);
+    rt_schedule();
     RT_EOK
 }
 
 /// 获取 semaphore 结构体
 /// @param semaphore 结构体
+/// @param timeout 等待时间（tick）：RT_WAITING_NO 不等待，小于0表示永久等待
 /// @return RT_EOK: 获取成功
-pub fn rt_sem_take(sem: Arc<Semaphore>, timeout: usize) -> RtErrT {
+///         RT_ETIMEOUT: 超时
+///         RT_ERROR: 信号量被删除或获取失败
+pub fn rt_sem_take(sem: Arc<Semaphore>, timeout: i32) -> RtErrT {
     let level = rt_hw_interrupt_disable();
-    if *sem.count.lock() > 0 {
-        *sem.count.lock() -= 1;
-        rt_hw_interrupt_enable(level);
-        RT_EOK
-    }
-    else {
-        if timeout == 0 {
-            rt_hw_interrupt_enable(level);
-            return RT_ETIMEOUT;
-        }
-        else {
-            let thread = rt_thread_self().unwrap();
-            thread.inner.exclusive_access().error = RT_EOK;
-            _ipc_list_suspend(sem.parent.exclusive_session(|ipc| ipc.clone()), thread.clone());
-            hprintln!("rt_sem_take: {:?}", thread);
-            hprintln!("进入计时，挂起");
-            if timeout > 0 {
-                let mut time = timeout as u32;
-                // 创建单次定时器（不是周期定时器）
-                let timer = Arc::new(Mutex::new(RtTimer::new(
-                    "rt_sem_take",
-                    0,
-                    0x0,  // 单次定时器，不是周期定时器
-                    Some(Box::new(move || {
-                        hprintln!("计时结束");
-                    })),
-                    timeout as u32,
-                    timeout as u32,
-                )));
-
-                hprintln!("计时即将开始");
-                // 启动定时器
-                timer::rt_timer_start(timer.clone());
-                hprintln!("计时开始");
-            }
+    {
+        let mut count = sem.count.lock();
+        if *count > 0 {
+            *count -= 1;
             rt_hw_interrupt_enable(level);
-            hprintln!("rt_sem_take: {:?}", thread);
-            hprintln!("正在计时");
-            rt_schedule();
-            if thread.inner.exclusive_access().error != RT_EOK {
-                return thread.inner.exclusive_access().error;
-            }
             return RT_EOK;
         }
     }
+
+    if timeout == RT_WAITING_NO {
+        rt_hw_interrupt_enable(level);
+        return RT_ETIMEOUT;
+    }
+
+    let thread = match rt_thread_self() {
+        Some(thread) => thread,
+        None => {
+            rt_hw_interrupt_enable(level);
+            return RT_ERROR;
+        }
+    };
+    // 释放者直接把信号量交给被唤醒的线程，计数不再增加
+    _ipc_wait(sem.parent.exclusive_session(|ipc| ipc.clone()), thread, timeout, level)
+}
+
+/// 尝试获取 semaphore 结构体（不等待）
+/// @param semaphore 结构体
+/// @return RT_EOK: 获取成功
+///         RT_ETIMEOUT: 信号量不可用
+pub fn rt_sem_trytake(sem: Arc<Semaphore>) -> RtErrT {
+    rt_sem_take(sem, RT_WAITING_NO)
 }
 
 
@@ -231,8 +430,9 @@ pub fn rt_sem_release(sem: Arc<Semaphore>) -> RtErrT {
         }
     }
     else {
-        if *sem.count.lock() < 0x10000u32 {
-            *sem.count.lock() += 1;
+        let mut count = sem.count.lock();
+        if *count < RT_SEM_VALUE_MAX as u32 {
+            *count += 1;
         }
         else {
             rt_hw_interrupt_enable(level);
@@ -245,3 +445,806 @@ pub fn rt_sem_release(sem: Arc<Semaphore>) -> RtErrT {
     }
     RT_EOK
 }
+
+
+/// 创建 mutex
+/// @param name 名称
+/// @return mutex 结构体
+pub fn rt_mutex_create(name: &str) -> Arc<RtMutex> {
+    let ipc_parent = _ipc_init(name, RT_OBJECT_CLASS_MUTEX, RT_IPC_FLAG_PRIO);
+    Arc::new(RtMutex {
+        parent: unsafe { RTIntrFreeCell::new(ipc_parent) },
+        inner: unsafe {
+            RTIntrFreeCell::new(RtMutexInner {
+                owner: None,
+                original_priority: 0xff,
+                hold: 0,
+            })
+        },
+    })
+}
+
+/// 删除 mutex
+/// 唤醒所有等待线程，等待线程的错误码被设置为 RT_ERROR
+/// @param mutex 结构体
+/// @return RT_EOK: 删除成功
+pub fn rt_mutex_delete(mutex: Arc<RtMutex>) -> RtErrT {
+    let level = rt_hw_interrupt_disable();
+    _ipc_list_resume_all_error(mutex.parent.exclusive_session(|ipc| ipc.clone()), RT_ERROR);
+    let owner = mutex.inner.exclusive_session(|inner| {
+        inner.hold = 0;
+        inner.owner.take()
+    });
+    if let Some(owner) = owner {
+        owner.inner.exclusive_access().taken_mutexes.retain(|m| !Arc::ptr_eq(m, &mutex));
+    }
+    rt_hw_interrupt_enable(level);
+    rt_schedule();
+    RT_EOK
+}
+
+/// 获取 mutex
+/// 同一线程可递归获取，持有次数上限为 RT_MUTEX_HOLD_MAX；
+/// 若 mutex 被更低优先级的线程持有，则将持有者的优先级提升到当前线程的优先级（优先级继承）
+/// @param mutex 结构体
+/// @param timeout 等待时间（tick）：RT_WAITING_NO 不等待，小于0表示永久等待
+/// @return RT_EOK: 获取成功
+///         RT_ETIMEOUT: 超时
+///         RT_EFULL: 递归持有次数溢出
+///         RT_ERROR: 获取失败
+pub fn rt_mutex_take(mutex: Arc<RtMutex>, timeout: i32) -> RtErrT {
+    let thread = match rt_thread_self() {
+        Some(thread) => thread,
+        None => return RT_ERROR,
+    };
+    let level = rt_hw_interrupt_disable();
+    thread.inner.exclusive_access().error = RT_EOK;
+
+    let owner = mutex.inner.exclusive_access().owner.clone();
+    match owner {
+        Some(owner) if Arc::ptr_eq(&owner, &thread) => {
+            // 同一线程递归获取
+            let mut inner = mutex.inner.exclusive_access();
+            if inner.hold == RT_MUTEX_HOLD_MAX {
+                drop(inner);
+                rt_hw_interrupt_enable(level);
+                return RT_EFULL;
+            }
+            inner.hold += 1;
+        }
+        None => {
+            // mutex 空闲，直接获取
+            let current_priority = thread.inner.exclusive_access().current_priority;
+            mutex.inner.exclusive_session(|inner| {
+                inner.owner = Some(thread.clone());
+                inner.original_priority = current_priority;
+                inner.hold = 1;
+            });
+            thread.inner.exclusive_access().taken_mutexes.push(mutex.clone());
+        }
+        Some(owner) => {
+            if timeout == RT_WAITING_NO {
+                thread.inner.exclusive_access().error = RT_ETIMEOUT;
+                rt_hw_interrupt_enable(level);
+                return RT_ETIMEOUT;
+            }
+            // 优先级继承：提升持有者的优先级
+            let priority = thread.inner.exclusive_access().current_priority;
+            let owner_priority = owner.inner.exclusive_access().current_priority;
+            if priority < owner_priority {
+                rt_thread_set_priority(owner.clone(), priority);
+            }
+
+            let ipc = mutex.parent.exclusive_session(|ipc| ipc.clone());
+            // 记录等待的 mutex，线程在等待中被删除时据此恢复持有者的优先级
+            thread.inner.exclusive_access().wait_mutex = Some(mutex.clone());
+            let error = _ipc_wait(ipc, thread.clone(), timeout, level);
+            thread.inner.exclusive_access().wait_mutex = None;
+            if error != RT_EOK {
+                // 等待失败（超时或 mutex 被删除），重新计算持有者应有的优先级
+                let level = rt_hw_interrupt_disable();
+                _mutex_update_owner_priority(&mutex);
+                rt_hw_interrupt_enable(level);
+                return error;
+            }
+            // 被唤醒时 mutex 已由释放者转交给当前线程
+            return RT_EOK;
+        }
+    }
+
+    rt_hw_interrupt_enable(level);
+    RT_EOK
+}
+
+/// 尝试获取 mutex（不等待）
+/// @param mutex 结构体
+/// @return RT_EOK: 获取成功
+///         RT_ETIMEOUT: mutex 被其他线程持有
+pub fn rt_mutex_trytake(mutex: Arc<RtMutex>) -> RtErrT {
+    rt_mutex_take(mutex, RT_WAITING_NO)
+}
+
+/// 释放 mutex
+/// 持有次数归零时恢复持有者的原始优先级，并将 mutex 转交给等待队列中优先级最高的线程
+/// @param mutex 结构体
+/// @return RT_EOK: 释放成功
+///         RT_ERROR: 当前线程不是持有者
+pub fn rt_mutex_release(mutex: Arc<RtMutex>) -> RtErrT {
+    let thread = match rt_thread_self() {
+        Some(thread) => thread,
+        None => return RT_ERROR,
+    };
+    let mut need_schedule = false;
+    let level = rt_hw_interrupt_disable();
+
+    let is_owner = mutex.inner.exclusive_access().owner.as_ref()
+        .map_or(false, |owner| Arc::ptr_eq(owner, &thread));
+    if !is_owner {
+        thread.inner.exclusive_access().error = RT_ERROR;
+        rt_hw_interrupt_enable(level);
+        return RT_ERROR;
+    }
+
+    let (hold, original_priority) = mutex.inner.exclusive_session(|inner| {
+        inner.hold -= 1;
+        (inner.hold, inner.original_priority)
+    });
+    if hold == 0 {
+        // 恢复持有者的原始优先级（仍持有的其他 mutex 的等待者的优先级继续继承）
+        thread.inner.exclusive_access().taken_mutexes.retain(|m| !Arc::ptr_eq(m, &mutex));
+        let priority = _mutex_inherited_priority(&thread, original_priority);
+        if thread.inner.exclusive_access().current_priority != priority {
+            rt_thread_set_priority(thread.clone(), priority);
+            need_schedule = true;
+        }
+
+        if _mutex_transfer(&mutex) {
+            need_schedule = true;
+        }
+    }
+
+    rt_hw_interrupt_enable(level);
+    if need_schedule {
+        rt_schedule();
+    }
+    RT_EOK
+}
+
+/// 将 mutex 转交给等待队列中的第一个线程（优先级最高），没有等待者时 mutex 变为空闲
+/// @return 是否有线程获得了 mutex
+fn _mutex_transfer(mutex: &Arc<RtMutex>) -> bool {
+    let ipc = mutex.parent.exclusive_session(|ipc| ipc.clone());
+    match _ipc_list_resume(ipc) {
+        Some(next) => {
+            let priority = next.inner.exclusive_access().current_priority;
+            mutex.inner.exclusive_session(|inner| {
+                inner.owner = Some(next.clone());
+                inner.original_priority = priority;
+                inner.hold = 1;
+            });
+            next.inner.exclusive_access().taken_mutexes.push(mutex.clone());
+            // 新持有者可能仍需继承剩余等待者的优先级
+            _mutex_update_owner_priority(mutex);
+            true
+        }
+        None => {
+            mutex.inner.exclusive_session(|inner| {
+                inner.owner = None;
+                inner.original_priority = 0xff;
+            });
+            false
+        }
+    }
+}
+
+/// 线程被删除或退出时处理其相关的 mutex（需在关中断时调用，线程已移出等待队列）
+/// 释放线程持有的全部 mutex 并转交给各自的等待者；
+/// 若线程正等待某个 mutex，重新计算该 mutex 持有者继承的优先级
+pub fn _mutex_thread_defunct(thread: &Arc<RtThread>) {
+    let (wait_mutex, taken_mutexes) = thread.inner.exclusive_session(|inner| {
+        (inner.wait_mutex.take(), core::mem::take(&mut inner.taken_mutexes))
+    });
+    if let Some(mutex) = wait_mutex {
+        _mutex_update_owner_priority(&mutex);
+    }
+    for mutex in taken_mutexes.iter() {
+        mutex.inner.exclusive_access().hold = 0;
+        _mutex_transfer(mutex);
+    }
+}
+
+/// 根据等待队列重新计算 mutex 持有者的优先级
+/// 持有者的优先级取原始优先级与其持有的所有 mutex 的最高优先级等待者中较高者（数值较小者）
+fn _mutex_update_owner_priority(mutex: &Arc<RtMutex>) {
+    let (owner, original_priority) = mutex.inner.exclusive_session(|inner| {
+        (inner.owner.clone(), inner.original_priority)
+    });
+    let owner = match owner {
+        Some(owner) => owner,
+        None => return,
+    };
+    let priority = _mutex_inherited_priority(&owner, original_priority);
+    if owner.inner.exclusive_access().current_priority != priority {
+        rt_thread_set_priority(owner, priority);
+    }
+}
+
+/// 计算线程从其持有的 mutex 的等待者处继承的优先级
+/// @param thread 线程
+/// @param priority 不考虑继承时线程的优先级
+/// @return priority 与所有等待者优先级中的最高者（数值较小者）
+pub fn _mutex_inherited_priority(thread: &Arc<RtThread>, mut priority: u8) -> u8 {
+    let mutexes = thread.inner.exclusive_access().taken_mutexes.clone();
+    for mutex in mutexes.iter() {
+        let ipc = mutex.parent.exclusive_session(|ipc| ipc.clone());
+        // 等待队列按优先级排序，队首即为最高优先级
+        let waiter_priority = ipc.thread_queue.exclusive_session(|queue| {
+            queue.first().map(|t| t.inner.exclusive_access().current_priority)
+        });
+        if let Some(waiter_priority) = waiter_priority {
+            if waiter_priority < priority {
+                priority = waiter_priority;
+            }
+        }
+    }
+    priority
+}
+
+/// 创建 event
+/// @param name 名称
+/// @return event 结构体
+pub fn rt_event_create(name: &str) -> Arc<RtEvent> {
+    let ipc_parent = _ipc_init(name, RT_OBJECT_CLASS_EVENT, RT_IPC_FLAG_PRIO);
+    Arc::new(RtEvent {
+        parent: unsafe { RTIntrFreeCell::new(ipc_parent) },
+        set: Mutex::new(0),
+    })
+}
+
+/// 删除 event
+/// 唤醒所有等待线程，等待线程的错误码被设置为 RT_ERROR
+/// @param event 结构体
+/// @return RT_EOK: 删除成功
+pub fn rt_event_delete(event: Arc<RtEvent>) -> RtErrT {
+    let level = rt_hw_interrupt_disable();
+    _ipc_list_resume_all_error(event.parent.exclusive_session(|ipc| ipc.clone()), RT_ERROR);
+    *event.set.lock() = 0;
+    rt_hw_interrupt_enable(level);
+    rt_schedule();
+    RT_EOK
+}
+
+/// 检查事件集合是否满足线程等待的条件
+/// @return 满足条件时返回实际收到的事件，否则返回 None
+fn _event_match(event_set: u32, set: u32, option: u8) -> Option<u32> {
+    if option & RT_EVENT_FLAG_AND != 0 {
+        if event_set & set == set {
+            return Some(set);
+        }
+    } else if option & RT_EVENT_FLAG_OR != 0 {
+        if event_set & set != 0 {
+            return Some(event_set & set);
+        }
+    }
+    None
+}
+
+/// 发送事件
+/// 将 set 中的事件置位，并唤醒所有条件已满足的等待线程
+/// @param event 结构体
+/// @param set 要发送的事件集合
+/// @return RT_EOK: 发送成功
+///         RT_ERROR: set 为 0
+pub fn rt_event_send(event: Arc<RtEvent>, set: u32) -> RtErrT {
+    if set == 0 {
+        return RT_ERROR;
+    }
+    let level = rt_hw_interrupt_disable();
+    *event.set.lock() |= set;
+
+    // 按队列顺序检查等待线程，条件满足则出队
+    let ipc = event.parent.exclusive_session(|ipc| ipc.clone());
+    let mut ready_threads = Vec::new();
+    ipc.thread_queue.exclusive_session(|queue| {
+        let mut i = 0;
+        while i < queue.len() {
+            let (wait_set, option) = {
+                let inner = queue[i].inner.exclusive_access();
+                (inner.event_set, inner.event_info)
+            };
+            let mut event_set = event.set.lock();
+            if let Some(recved) = _event_match(*event_set, wait_set, option) {
+                // 自动清除已收到的事件，后续等待者看到的是清除后的事件集合
+                if option & RT_EVENT_FLAG_CLEAR != 0 {
+                    *event_set &= !recved;
+                }
+                drop(event_set);
+                let thread = queue.remove(i);
+                thread.inner.exclusive_access().event_set = recved;
+                ready_threads.push(thread);
+            } else {
+                i += 1;
+            }
+        }
+    });
+
+    let need_schedule = !ready_threads.is_empty();
+    for thread in ready_threads {
+        _ipc_stop_timeout(&thread);
+        rt_thread_resume(thread);
+    }
+    rt_hw_interrupt_enable(level);
+    if need_schedule {
+        rt_schedule();
+    }
+    RT_EOK
+}
+
+/// 接收事件
+/// @param event 结构体
+/// @param set 感兴趣的事件集合
+/// @param option 接收选项：RT_EVENT_FLAG_AND 或 RT_EVENT_FLAG_OR，可与 RT_EVENT_FLAG_CLEAR 组合
+/// @param timeout 等待时间（tick）：RT_WAITING_NO 不等待，小于0表示永久等待
+/// @param recved 实际收到的事件集合
+/// @return RT_EOK: 接收成功
+///         RT_ETIMEOUT: 超时
+///         RT_EINVAL: 参数错误
+///         RT_ERROR: 接收失败
+pub fn rt_event_recv(event: Arc<RtEvent>, set: u32, option: u8, timeout: i32, recved: &mut u32) -> RtErrT {
+    if set == 0 {
+        return RT_ERROR;
+    }
+    // AND 与 OR 必须且只能选择其一
+    let logic = option & (RT_EVENT_FLAG_AND | RT_EVENT_FLAG_OR);
+    if logic != RT_EVENT_FLAG_AND && logic != RT_EVENT_FLAG_OR {
+        return RT_EINVAL;
+    }
+    let thread = match rt_thread_self() {
+        Some(thread) => thread,
+        None => return RT_ERROR,
+    };
+
+    let level = rt_hw_interrupt_disable();
+    thread.inner.exclusive_access().error = RT_EOK;
+
+    let matched = {
+        let mut event_set = event.set.lock();
+        let matched = _event_match(*event_set, set, option);
+        if let Some(matched) = matched {
+            if option & RT_EVENT_FLAG_CLEAR != 0 {
+                *event_set &= !matched;
+            }
+        }
+        matched
+    };
+    if let Some(matched) = matched {
+        *recved = matched;
+        rt_hw_interrupt_enable(level);
+        return RT_EOK;
+    }
+
+    if timeout == RT_WAITING_NO {
+        thread.inner.exclusive_access().error = RT_ETIMEOUT;
+        rt_hw_interrupt_enable(level);
+        return RT_ETIMEOUT;
+    }
+
+    // 记录等待条件，由 rt_event_send 判断并唤醒
+    {
+        let mut inner = thread.inner.exclusive_access();
+        inner.event_set = set;
+        inner.event_info = option;
+    }
+    let ipc = event.parent.exclusive_session(|ipc| ipc.clone());
+    let error = _ipc_wait(ipc, thread.clone(), timeout, level);
+    if error != RT_EOK {
+        return error;
+    }
+    *recved = thread.inner.exclusive_access().event_set;
+    RT_EOK
+}
+
+/// 创建 mailbox
+/// @param name 名称
+/// @param size 邮箱容量（邮件数量）
+/// @return mailbox 结构体，容量为0或超过 RT_MB_ENTRY_MAX 时返回 None
+pub fn rt_mb_create(name: &str, size: usize) -> Option<Arc<RtMailbox>> {
+    if size == 0 || size > RT_MB_ENTRY_MAX as usize {
+        return None;
+    }
+    let mut msg_pool = Vec::with_capacity(size);
+    msg_pool.resize(size, 0);
+    Some(Arc::new(RtMailbox {
+        parent: unsafe { RTIntrFreeCell::new(_ipc_init(name, RT_OBJECT_CLASS_MAILBOX, RT_IPC_FLAG_PRIO)) },
+        suspend_sender: unsafe { RTIntrFreeCell::new(_ipc_init(name, RT_OBJECT_CLASS_MAILBOX, RT_IPC_FLAG_PRIO)) },
+        inner: unsafe {
+            RTIntrFreeCell::new(RtMailboxInner {
+                msg_pool,
+                size: size as u16,
+                entry: 0,
+                in_offset: 0,
+                out_offset: 0,
+            })
+        },
+    }))
+}
+
+/// 删除 mailbox
+/// 唤醒所有等待的发送和接收线程，其错误码被设置为 RT_ERROR
+/// @param mb 结构体
+/// @return RT_EOK: 删除成功
+pub fn rt_mb_delete(mb: Arc<RtMailbox>) -> RtErrT {
+    let level = rt_hw_interrupt_disable();
+    _ipc_list_resume_all_error(mb.parent.exclusive_session(|ipc| ipc.clone()), RT_ERROR);
+    _ipc_list_resume_all_error(mb.suspend_sender.exclusive_session(|ipc| ipc.clone()), RT_ERROR);
+    mb.inner.exclusive_session(|inner| {
+        inner.entry = 0;
+        inner.in_offset = 0;
+        inner.out_offset = 0;
+    });
+    rt_hw_interrupt_enable(level);
+    rt_schedule();
+    RT_EOK
+}
+
+/// 等待后更新剩余等待时间
+/// @param timeout 原等待时间，小于0表示永久等待
+/// @param start_tick 开始等待时的 tick
+pub fn _ipc_remain_timeout(timeout: i32, start_tick: u32) -> i32 {
+    if timeout <= 0 {
+        return timeout;
+    }
+    let elapsed = rt_tick_get().wrapping_sub(start_tick);
+    if elapsed >= timeout as u32 {
+        0
+    } else {
+        timeout - elapsed as i32
+    }
+}
+
+/// 发送邮件，邮箱满时等待
+/// @param mb 结构体
+/// @param value 邮件内容
+/// @param timeout 等待时间（tick）：RT_WAITING_NO 不等待，小于0表示永久等待
+/// @return RT_EOK: 发送成功
+///         RT_EFULL: 邮箱已满（不等待时）
+///         RT_ETIMEOUT: 超时
+///         RT_ERROR: 发送失败
+pub fn rt_mb_send_wait(mb: Arc<RtMailbox>, value: usize, timeout: i32) -> RtErrT {
+    let mut timeout = timeout;
+    let mut level = rt_hw_interrupt_disable();
+
+    // 邮箱已满，挂起发送线程
+    while mb.inner.exclusive_session(|inner| inner.entry == inner.size) {
+        if timeout == RT_WAITING_NO {
+            rt_hw_interrupt_enable(level);
+            return RT_EFULL;
+        }
+        let thread = match rt_thread_self() {
+            Some(thread) => thread,
+            None => {
+                rt_hw_interrupt_enable(level);
+                return RT_ERROR;
+            }
+        };
+        let start_tick = rt_tick_get();
+        let sender = mb.suspend_sender.exclusive_session(|ipc| ipc.clone());
+        let error = _ipc_wait(sender, thread, timeout, level);
+        if error != RT_EOK {
+            return error;
+        }
+        timeout = _ipc_remain_timeout(timeout, start_tick);
+        level = rt_hw_interrupt_disable();
+    }
+
+    mb.inner.exclusive_session(|inner| {
+        let in_offset = inner.in_offset as usize;
+        inner.msg_pool[in_offset] = value;
+        inner.in_offset = (inner.in_offset + 1) % inner.size;
+        inner.entry += 1;
+    });
+
+    // 唤醒一个接收线程
+    let need_schedule = _ipc_list_resume(mb.parent.exclusive_session(|ipc| ipc.clone())).is_some();
+    rt_hw_interrupt_enable(level);
+    if need_schedule {
+        rt_schedule();
+    }
+    RT_EOK
+}
+
+/// 发送邮件（不等待，可在中断中调用）
+/// @param mb 结构体
+/// @param value 邮件内容
+/// @return RT_EOK: 发送成功
+///         RT_EFULL: 邮箱已满
+pub fn rt_mb_send(mb: Arc<RtMailbox>, value: usize) -> RtErrT {
+    rt_mb_send_wait(mb, value, RT_WAITING_NO)
+}
+
+/// 发送紧急邮件
+/// 紧急邮件被放到队首，下一次接收时立即取出
+/// @param mb 结构体
+/// @param value 邮件内容
+/// @return RT_EOK: 发送成功
+///         RT_EFULL: 邮箱已满
+pub fn rt_mb_urgent(mb: Arc<RtMailbox>, value: usize) -> RtErrT {
+    let level = rt_hw_interrupt_disable();
+    let full = mb.inner.exclusive_session(|inner| {
+        if inner.entry == inner.size {
+            return true;
+        }
+        inner.out_offset = if inner.out_offset == 0 { inner.size - 1 } else { inner.out_offset - 1 };
+        let out_offset = inner.out_offset as usize;
+        inner.msg_pool[out_offset] = value;
+        inner.entry += 1;
+        false
+    });
+    if full {
+        rt_hw_interrupt_enable(level);
+        return RT_EFULL;
+    }
+
+    let need_schedule = _ipc_list_resume(mb.parent.exclusive_session(|ipc| ipc.clone())).is_some();
+    rt_hw_interrupt_enable(level);
+    if need_schedule {
+        rt_schedule();
+    }
+    RT_EOK
+}
+
+/// 接收邮件，邮箱空时等待
+/// @param mb 结构体
+/// @param value 收到的邮件内容
+/// @param timeout 等待时间（tick）：RT_WAITING_NO 不等待，小于0表示永久等待
+/// @return RT_EOK: 接收成功
+///         RT_ETIMEOUT: 超时
+///         RT_ERROR: 接收失败
+pub fn rt_mb_recv(mb: Arc<RtMailbox>, value: &mut usize, timeout: i32) -> RtErrT {
+    let mut timeout = timeout;
+    let mut level = rt_hw_interrupt_disable();
+
+    // 邮箱为空，挂起接收线程
+    while mb.inner.exclusive_session(|inner| inner.entry == 0) {
+        if timeout == RT_WAITING_NO {
+            rt_hw_interrupt_enable(level);
+            return RT_ETIMEOUT;
+        }
+        let thread = match rt_thread_self() {
+            Some(thread) => thread,
+            None => {
+                rt_hw_interrupt_enable(level);
+                return RT_ERROR;
+            }
+        };
+        let start_tick = rt_tick_get();
+        let receiver = mb.parent.exclusive_session(|ipc| ipc.clone());
+        let error = _ipc_wait(receiver, thread, timeout, level);
+        if error != RT_EOK {
+            return error;
+        }
+        timeout = _ipc_remain_timeout(timeout, start_tick);
+        level = rt_hw_interrupt_disable();
+    }
+
+    *value = mb.inner.exclusive_session(|inner| {
+        let value = inner.msg_pool[inner.out_offset as usize];
+        inner.out_offset = (inner.out_offset + 1) % inner.size;
+        inner.entry -= 1;
+        value
+    });
+
+    // 唤醒一个发送线程
+    let need_schedule = _ipc_list_resume(mb.suspend_sender.exclusive_session(|ipc| ipc.clone())).is_some();
+    rt_hw_interrupt_enable(level);
+    if need_schedule {
+        rt_schedule();
+    }
+    RT_EOK
+}
+
+/// 创建 message queue
+/// @param name 名称
+/// @param msg_size 单条消息的最大长度（字节）
+/// @param max_msgs 最大消息数量
+/// @param flag 等待队列排序方式：RT_IPC_FLAG_FIFO 或 RT_IPC_FLAG_PRIO
+/// @return message queue 结构体，参数不合法时返回 None
+pub fn rt_mq_create(name: &str, msg_size: usize, max_msgs: usize, flag: u8) -> Option<Arc<RtMessageQueue>> {
+    if msg_size == 0 || max_msgs == 0 || max_msgs > RT_MQ_ENTRY_MAX as usize {
+        return None;
+    }
+    if flag != RT_IPC_FLAG_FIFO && flag != RT_IPC_FLAG_PRIO {
+        return None;
+    }
+    // 消息长度按 RT_ALIGN_SIZE 对齐
+    let msg_size = (msg_size + rtconfig::RT_ALIGN_SIZE as usize - 1) & !(rtconfig::RT_ALIGN_SIZE as usize - 1);
+    let mut msg_pool = Vec::with_capacity(msg_size * max_msgs);
+    msg_pool.resize(msg_size * max_msgs, 0);
+    let mut msg_len = Vec::with_capacity(max_msgs);
+    msg_len.resize(max_msgs, 0);
+    Some(Arc::new(RtMessageQueue {
+        parent: unsafe { RTIntrFreeCell::new(_ipc_init(name, RT_OBJECT_CLASS_MESSAGEQUEUE, flag)) },
+        suspend_sender: unsafe { RTIntrFreeCell::new(_ipc_init(name, RT_OBJECT_CLASS_MESSAGEQUEUE, flag)) },
+        inner: unsafe {
+            RTIntrFreeCell::new(RtMessageQueueInner {
+                msg_pool,
+                msg_len,
+                msg_size,
+                max_msgs: max_msgs as u16,
+                entry: 0,
+                head: 0,
+                tail: 0,
+            })
+        },
+    }))
+}
+
+/// 删除 message queue
+/// 唤醒所有等待的发送和接收线程，其错误码被设置为 RT_ERROR
+/// @param mq 结构体
+/// @return RT_EOK: 删除成功
+pub fn rt_mq_delete(mq: Arc<RtMessageQueue>) -> RtErrT {
+    let level = rt_hw_interrupt_disable();
+    _ipc_list_resume_all_error(mq.parent.exclusive_session(|ipc| ipc.clone()), RT_ERROR);
+    _ipc_list_resume_all_error(mq.suspend_sender.exclusive_session(|ipc| ipc.clone()), RT_ERROR);
+    mq.inner.exclusive_session(|inner| {
+        inner.entry = 0;
+        inner.head = 0;
+        inner.tail = 0;
+    });
+    rt_hw_interrupt_enable(level);
+    rt_schedule();
+    RT_EOK
+}
+
+/// 发送消息，队列满时等待
+/// @param mq 结构体
+/// @param buffer 消息内容，长度不能超过 msg_size
+/// @param timeout 等待时间（tick）：RT_WAITING_NO 不等待，小于0表示永久等待
+/// @return RT_EOK: 发送成功
+///         RT_EFULL: 队列已满（不等待时）
+///         RT_ETIMEOUT: 超时
+///         RT_ERROR: 消息长度不合法或发送失败
+pub fn rt_mq_send_wait(mq: Arc<RtMessageQueue>, buffer: &[u8], timeout: i32) -> RtErrT {
+    if buffer.is_empty() || buffer.len() > mq.inner.exclusive_access().msg_size {
+        return RT_ERROR;
+    }
+    let mut timeout = timeout;
+    let mut level = rt_hw_interrupt_disable();
+
+    // 队列已满，挂起发送线程
+    while mq.inner.exclusive_session(|inner| inner.entry == inner.max_msgs) {
+        if timeout == RT_WAITING_NO {
+            rt_hw_interrupt_enable(level);
+            return RT_EFULL;
+        }
+        let thread = match rt_thread_self() {
+            Some(thread) => thread,
+            None => {
+                rt_hw_interrupt_enable(level);
+                return RT_ERROR;
+            }
+        };
+        let start_tick = rt_tick_get();
+        let sender = mq.suspend_sender.exclusive_session(|ipc| ipc.clone());
+        let error = _ipc_wait(sender, thread, timeout, level);
+        if error != RT_EOK {
+            return error;
+        }
+        timeout = _ipc_remain_timeout(timeout, start_tick);
+        level = rt_hw_interrupt_disable();
+    }
+
+    mq.inner.exclusive_session(|inner| {
+        let tail = inner.tail;
+        inner.write_slot(tail, buffer);
+        inner.tail = (inner.tail + 1) % inner.max_msgs;
+        inner.entry += 1;
+    });
+
+    // 唤醒一个接收线程
+    let need_schedule = _ipc_list_resume(mq.parent.exclusive_session(|ipc| ipc.clone())).is_some();
+    rt_hw_interrupt_enable(level);
+    if need_schedule {
+        rt_schedule();
+    }
+    RT_EOK
+}
+
+/// 发送消息（不等待，可在中断中调用）
+/// @param mq 结构体
+/// @param buffer 消息内容
+/// @return RT_EOK: 发送成功
+///         RT_EFULL: 队列已满
+///         RT_ERROR: 消息长度不合法
+pub fn rt_mq_send(mq: Arc<RtMessageQueue>, buffer: &[u8]) -> RtErrT {
+    rt_mq_send_wait(mq, buffer, RT_WAITING_NO)
+}
+
+/// 发送紧急消息
+/// 紧急消息被放到队首，下一次接收时立即取出
+/// @param mq 结构体
+/// @param buffer 消息内容
+/// @return RT_EOK: 发送成功
+///         RT_EFULL: 队列已满
+///         RT_ERROR: 消息长度不合法
+pub fn rt_mq_urgent(mq: Arc<RtMessageQueue>, buffer: &[u8]) -> RtErrT {
+    if buffer.is_empty() || buffer.len() > mq.inner.exclusive_access().msg_size {
+        return RT_ERROR;
+    }
+    let level = rt_hw_interrupt_disable();
+    let full = mq.inner.exclusive_session(|inner| {
+        if inner.entry == inner.max_msgs {
+            return true;
+        }
+        inner.head = if inner.head == 0 { inner.max_msgs - 1 } else { inner.head - 1 };
+        let head = inner.head;
+        inner.write_slot(head, buffer);
+        inner.entry += 1;
+        false
+    });
+    if full {
+        rt_hw_interrupt_enable(level);
+        return RT_EFULL;
+    }
+
+    let need_schedule = _ipc_list_resume(mq.parent.exclusive_session(|ipc| ipc.clone())).is_some();
+    rt_hw_interrupt_enable(level);
+    if need_schedule {
+        rt_schedule();
+    }
+    RT_EOK
+}
+
+/// 接收消息，队列空时等待
+/// 消息长度超过 buffer 时只拷贝 buffer 能容纳的部分
+/// @param mq 结构体
+/// @param buffer 接收缓冲区
+/// @param timeout 等待时间（tick）：RT_WAITING_NO 不等待，小于0表示永久等待
+/// @param recv_len 实际拷贝的字节数
+/// @return RT_EOK: 接收成功
+///         RT_ETIMEOUT: 超时
+///         RT_ERROR: 接收失败
+pub fn rt_mq_recv(mq: Arc<RtMessageQueue>, buffer: &mut [u8], timeout: i32, recv_len: &mut usize) -> RtErrT {
+    let mut timeout = timeout;
+    let mut level = rt_hw_interrupt_disable();
+
+    // 队列为空，挂起接收线程
+    while mq.inner.exclusive_session(|inner| inner.entry == 0) {
+        if timeout == RT_WAITING_NO {
+            rt_hw_interrupt_enable(level);
+            return RT_ETIMEOUT;
+        }
+        let thread = match rt_thread_self() {
+            Some(thread) => thread,
+            None => {
+                rt_hw_interrupt_enable(level);
+                return RT_ERROR;
+            }
+        };
+        let start_tick = rt_tick_get();
+        let receiver = mq.parent.exclusive_session(|ipc| ipc.clone());
+        let error = _ipc_wait(receiver, thread, timeout, level);
+        if error != RT_EOK {
+            return error;
+        }
+        timeout = _ipc_remain_timeout(timeout, start_tick);
+        level = rt_hw_interrupt_disable();
+    }
+
+    *recv_len = mq.inner.exclusive_session(|inner| {
+        let head = inner.head;
+        let len = inner.read_slot(head, buffer);
+        inner.head = (inner.head + 1) % inner.max_msgs;
+        inner.entry -= 1;
+        len
+    });
+
+    // 唤醒一个发送线程
+    let need_schedule = _ipc_list_resume(mq.suspend_sender.exclusive_session(|ipc| ipc.clone())).is_some();
+    rt_hw_interrupt_enable(level);
+    if need_schedule {
+        rt_schedule();
+    }
+    RT_EOK
+}
@@ -61,6 +61,30 @@ impl<T> RTIntrFreeCell<T> {
         }
     }
 
+    /// 尝试获取一个中断安全的RefMut，已被借用时返回 None 而不是 panic
+    /// 
+    /// 用于异常处理等可能打断持有者的场合
+    /// 
+    /// 使用示例:
+    /// ```rust
+    /// if let Some(mut data) = DATA.try_exclusive_access() {
+    ///     *data += 1;
+    /// }
+    /// ```
+    pub fn try_exclusive_access(&self) -> Option<RTIntrRefMut<'_, T>> {
+        let level = rt_hw_interrupt_disable();
+        match self.inner.try_borrow_mut() {
+            Ok(inner) => Some(RTIntrRefMut {
+                inner: Some(inner),
+                level,
+            }),
+            Err(_) => {
+                rt_hw_interrupt_enable(level);
+                None
+            }
+        }
+    }
+
     /// 在独占访问期间执行一个闭包
     /// 
     /// 使用示例:
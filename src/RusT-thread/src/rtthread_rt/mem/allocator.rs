@@ -1,42 +1,192 @@
 //! 内存分配器
 //! 
 //! 定义了内存分配器，并给出了对应的init_heap函数(堆初始化函数)
-//! 默认使用buddy_system_allocator
+//! 堆的范围由链接脚本 memory.x 中的符号决定
+//! 默认使用buddy_system_allocator，也可通过特性选择good_memory_allocator或slab
+//! 所选分配器外包一层 RtGlobalAlloc，使静态线程的线程对象可以分配在调用者提供的静态缓冲区中（见 with_static_arena）
 
 #![warn(unused_imports)]
 
-use core::sync::atomic::{AtomicBool, Ordering};
+use core::alloc::{GlobalAlloc, Layout};
+use core::ops::Deref;
+use core::ptr;
+use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use cortex_m_semihosting::hprintln;
 
+use crate::rtthread_rt::hardware::{rt_hw_interrupt_disable, rt_hw_interrupt_enable};
+
 // Global allocator implementation
 
 // Initialize heap status
 static HEAP_INITIALIZED: AtomicBool = AtomicBool::new(false);
-// Re-export from the original file
-const HEAP_START: usize = 0x2000_0400;  // RAM 起始地址
-const HEAP_SIZE: usize = 32 * 1024;     // 使用 32KB 作为堆大小，留出足够空间给其他用途
 
+// 链接符号：堆的范围由链接脚本决定，而不是写死的地址
+// __sheap、__ebss、_stack_start 由 cortex-m-rt 的 link.x 提供，
+// _heap_end、_stack_size、_heap_min_size 在 memory.x 中定义
+unsafe extern "C" {
+    static __ebss: u8;
+    static __sheap: u8;
+    static _heap_end: u8;
+    static _stack_start: u8;
+    static _stack_size: u8;
+    static _heap_min_size: u8;
+}
+
+/// 获取堆的起始地址和大小
+/// @return (起始地址, 大小)，范围为 __sheap 到主栈预留区底部
+pub fn heap_range() -> (usize, usize) {
+    let start = &raw const __sheap as usize;
+    let end = &raw const _heap_end as usize;
+    (start, end.saturating_sub(start))
+}
+
+/// 启动时检查堆是否与静态变量或主栈重叠，重叠时打印原因并停机
+fn heap_check(start: usize, size: usize) {
+    let ebss = &raw const __ebss as usize;
+    let stack_start = &raw const _stack_start as usize;
+    // 绝对符号，其地址即为数值
+    let stack_size = &raw const _stack_size as usize;
+    let min_size = &raw const _heap_min_size as usize;
+    let end = start + size;
+    let msp = cortex_m::register::msp::read() as usize;
+
+    let error = if start < ebss {
+        Some("heap overlaps .data/.bss")
+    } else if end > stack_start - stack_size {
+        Some("heap overlaps the main stack reservation")
+    } else if size < min_size {
+        Some("heap is smaller than _heap_min_size")
+    } else if msp < end {
+        Some("main stack pointer is inside the heap")
+    } else {
+        None
+    };
+
+    if let Some(error) = error {
+        let _ = hprintln!("init_heap: {}! heap: 0x{:x}..0x{:x}, __ebss: 0x{:x}, stack: 0x{:x}..0x{:x}, msp: 0x{:x}",
+            error, start, end, ebss, stack_start - stack_size, stack_start, msp);
+        panic!("init_heap: {}", error);
+    }
+}
+
+// 静态区域：非0时全局分配器从 [STATIC_ARENA_NEXT, STATIC_ARENA_END) 顺序分配，而不是从堆上分配
+static STATIC_ARENA_NEXT: AtomicUsize = AtomicUsize::new(0);
+static STATIC_ARENA_END: AtomicUsize = AtomicUsize::new(0);
+
+/// 地址是否在堆上
+fn in_heap(ptr: *mut u8) -> bool {
+    let (start, size) = heap_range();
+    (start..start + size).contains(&(ptr as usize))
+}
+
+/// 从静态区域中按对齐要求顺序分配，空间不足时返回空指针
+fn static_arena_alloc(layout: Layout) -> *mut u8 {
+    let next = STATIC_ARENA_NEXT.load(Ordering::Relaxed);
+    let start = (next + layout.align() - 1) & !(layout.align() - 1);
+    match start.checked_add(layout.size()) {
+        Some(end) if end <= STATIC_ARENA_END.load(Ordering::Relaxed) => {
+            STATIC_ARENA_NEXT.store(end, Ordering::Relaxed);
+            start as *mut u8
+        }
+        _ => ptr::null_mut(),
+    }
+}
+
+/// 在静态缓冲区中执行分配
+/// 执行 f 期间（关中断）全局分配器的分配都来自 buffer，不会访问堆；buffer 空间不足时分配失败
+/// 其中分配的内存释放时不会归还，buffer 此后也不能再用于其他用途
+/// @param buffer 静态缓冲区
+/// @param f 需要在静态缓冲区中分配的操作
+pub(crate) fn with_static_arena<R>(buffer: &'static mut [u8], f: impl FnOnce() -> R) -> R {
+    let level = rt_hw_interrupt_disable();
+    assert!(STATIC_ARENA_END.load(Ordering::Relaxed) == 0, "with_static_arena: nested static arena");
+    let start = buffer.as_mut_ptr() as usize;
+    STATIC_ARENA_NEXT.store(start, Ordering::Relaxed);
+    STATIC_ARENA_END.store(start + buffer.len(), Ordering::Relaxed);
+    let result = f();
+    STATIC_ARENA_END.store(0, Ordering::Relaxed);
+    rt_hw_interrupt_enable(level);
+    result
+}
+
+/// 全局分配器
+/// 转发给所选的堆分配器；静态区域生效时改从静态缓冲区分配，释放不在堆上的内存时直接忽略
+pub struct RtGlobalAlloc<A> {
+    heap: A,
+}
+
+impl<A> RtGlobalAlloc<A> {
+    pub const fn new(heap: A) -> Self {
+        RtGlobalAlloc { heap }
+    }
+}
 
+impl<A> Deref for RtGlobalAlloc<A> {
+    type Target = A;
+
+    fn deref(&self) -> &A {
+        &self.heap
+    }
+}
+
+unsafe impl<A: GlobalAlloc> GlobalAlloc for RtGlobalAlloc<A> {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if STATIC_ARENA_END.load(Ordering::Relaxed) != 0 {
+            return static_arena_alloc(layout);
+        }
+        unsafe { self.heap.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        // 静态缓冲区中的内存不归还
+        if in_heap(ptr) {
+            unsafe { self.heap.dealloc(ptr, layout) }
+        }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        if in_heap(ptr) && STATIC_ARENA_END.load(Ordering::Relaxed) == 0 {
+            return unsafe { self.heap.realloc(ptr, layout, new_size) };
+        }
+        // 静态缓冲区中的内存无法原地扩展，分配新内存后复制
+        unsafe {
+            let new_layout = Layout::from_size_align_unchecked(new_size, layout.align());
+            let new_ptr = self.alloc(new_layout);
+            if !new_ptr.is_null() {
+                ptr::copy_nonoverlapping(ptr, new_ptr, layout.size().min(new_size));
+                self.dealloc(ptr, layout);
+            }
+            new_ptr
+        }
+    }
+}
 
 // Declare global allocators based on the selected feature
 // 确保两个分配器是互斥的
 
-#[cfg(all(feature = "good_memory_allocator", not(feature = "buddy_system_allocator")))]
+#[cfg(all(feature = "good_memory_allocator", not(feature = "buddy_system_allocator"), not(feature = "slab")))]
 use good_memory_allocator::SpinLockedAllocator;
 
-#[cfg(all(feature = "buddy_system_allocator", not(feature = "good_memory_allocator")))]
+#[cfg(all(feature = "buddy_system_allocator", not(feature = "good_memory_allocator"), not(feature = "slab")))]
 use buddy_system_allocator::LockedHeap;
 
-#[cfg(all(feature = "good_memory_allocator", not(feature = "buddy_system_allocator")))]
+#[cfg(all(feature = "good_memory_allocator", not(feature = "buddy_system_allocator"), not(feature = "slab")))]
 #[global_allocator]
-static ALLOCATOR: SpinLockedAllocator = SpinLockedAllocator::empty();
+static ALLOCATOR: RtGlobalAlloc<SpinLockedAllocator> = RtGlobalAlloc::new(SpinLockedAllocator::empty());
 
-#[cfg(all(feature = "buddy_system_allocator", not(feature = "good_memory_allocator")))]
+#[cfg(all(feature = "buddy_system_allocator", not(feature = "good_memory_allocator"), not(feature = "slab")))]
 #[global_allocator]
-static HEAP_ALLOCATOR: LockedHeap<32> = LockedHeap::<32>::empty();
+static HEAP_ALLOCATOR: RtGlobalAlloc<LockedHeap<32>> = RtGlobalAlloc::new(LockedHeap::<32>::empty());
+
+#[cfg(all(feature = "slab", not(feature = "good_memory_allocator"), not(feature = "buddy_system_allocator")))]
+#[global_allocator]
+pub(crate) static SLAB_ALLOCATOR: RtGlobalAlloc<super::slab::SlabAllocator> = RtGlobalAlloc::new(super::slab::SlabAllocator::empty());
 
 /// 初始化堆内存（一定要在main函数之前调用）
 /// 
+/// 堆的范围来自链接符号（见 heap_range），初始化前会检查其不与静态变量和主栈重叠，
+/// 检查失败时打印原因并 panic
+/// 
 /// 使用示例:
 /// ```rust
 /// init_heap();
@@ -45,18 +195,25 @@ pub fn init_heap() {
     // hprintln!("Initializing heap...");
     if !HEAP_INITIALIZED.load(Ordering::SeqCst) {
         // hprintln!("Heap not initialized.");
+        let (heap_start, heap_size) = heap_range();
+        heap_check(heap_start, heap_size);
         unsafe {
-            #[cfg(all(feature = "good_memory_allocator", not(feature = "buddy_system_allocator")))]
+            #[cfg(all(feature = "good_memory_allocator", not(feature = "buddy_system_allocator"), not(feature = "slab")))]
             {
-                ALLOCATOR.init(HEAP_START, HEAP_SIZE);
+                ALLOCATOR.init(heap_start, heap_size);
             }
             
-            #[cfg(all(feature = "buddy_system_allocator", not(feature = "good_memory_allocator")))]
+            #[cfg(all(feature = "buddy_system_allocator", not(feature = "good_memory_allocator"), not(feature = "slab")))]
             {
                 // hprintln!("Initializing buddy_system_allocator...");
-                HEAP_ALLOCATOR.lock().init(HEAP_START, HEAP_SIZE);
+                HEAP_ALLOCATOR.lock().init(heap_start, heap_size);
                 // hprintln!("buddy_system_allocator initialized.");
             }
+
+            #[cfg(all(feature = "slab", not(feature = "good_memory_allocator"), not(feature = "buddy_system_allocator")))]
+            {
+                SLAB_ALLOCATOR.init(heap_start, heap_size);
+            }
         }
         HEAP_INITIALIZED.store(true, Ordering::SeqCst);
     }
@@ -64,8 +221,12 @@ pub fn init_heap() {
 }
 
 // 编译时检查：确保只启用了一个分配器
-#[cfg(all(feature = "good_memory_allocator", feature = "buddy_system_allocator"))]
-compile_error!("不能同时启用两个内存分配器！请只选择一个：good_memory_allocator 或 buddy_system_allocator");
+#[cfg(any(
+    all(feature = "good_memory_allocator", feature = "buddy_system_allocator"),
+    all(feature = "good_memory_allocator", feature = "slab"),
+    all(feature = "buddy_system_allocator", feature = "slab"),
+))]
+compile_error!("不能同时启用多个内存分配器！请只选择一个：good_memory_allocator、buddy_system_allocator 或 slab");
 
-#[cfg(not(any(feature = "good_memory_allocator", feature = "buddy_system_allocator")))]
-compile_error!("必须启用一个内存分配器！请选择：good_memory_allocator 或 buddy_system_allocator");
+#[cfg(not(any(feature = "good_memory_allocator", feature = "buddy_system_allocator", feature = "slab")))]
+compile_error!("必须启用一个内存分配器！请选择：good_memory_allocator、buddy_system_allocator 或 slab");
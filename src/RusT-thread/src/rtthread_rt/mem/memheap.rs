@@ -0,0 +1,157 @@
+//! 多区域内存堆（memheap）模块
+//!
+//! 参考 RT-Thread 的 memheap.c，可以把多块不连续的内存区域（如 RAM 与 CCMRAM）
+//! 注册为内存堆，每块区域使用小内存管理算法（small_mem_impl）管理。
+//! 分配时按注册顺序依次尝试各区域，也可以通过区域标签指定区域。
+//!
+//! 系统区域的边界来自 memory.x 中定义的链接符号，而不是写死的地址。
+//!
+//! 结构体：RtMemheap
+//! 函数：rt_memheap_init、rt_system_memheap_init、rt_memheap_alloc、rt_memheap_alloc_tag、rt_memheap_free、rt_memheap_info
+
+#![warn(unused_imports)]
+extern crate alloc;
+
+use core::ptr;
+use alloc::vec::Vec;
+use lazy_static::lazy_static;
+
+use crate::rtthread_rt::rtdef::{RtErrT, RT_EOK, RT_ERROR};
+use crate::rtthread_rt::kservice::RTIntrFreeCell;
+use crate::rtthread_rt::mem::small_mem_impl::{rt_smem_init, rt_smem_alloc, rt_smem_free, RTSmemT};
+
+/// SRAM 区域标签
+pub const RT_MEMHEAP_TAG_SRAM: u8 = 0;
+/// CCMRAM 区域标签（CPU 独占的高速内存，DMA 不可访问）
+pub const RT_MEMHEAP_TAG_CCM: u8 = 1;
+
+// memory.x 中定义的区域边界：SRAM 区域位于全局堆与主栈预留区之间，CCMRAM 区域为整个 CCMRAM
+unsafe extern "C" {
+    static mut _ssramheap: u8;
+    static mut _esramheap: u8;
+    static mut _sccmheap: u8;
+    static mut _eccmheap: u8;
+}
+
+/// 内存堆区域
+pub struct RtMemheap {
+    /// 区域标签，分配时可按标签选择区域
+    pub tag: u8,
+    /// 区域起始地址
+    pub start: usize,
+    /// 区域大小
+    pub size: usize,
+    /// 区域的小内存管理对象
+    pub smem: RTSmemT,
+}
+
+// 区域只在关中断时通过 RT_MEMHEAP_LIST 访问
+unsafe impl Send for RtMemheap {}
+
+lazy_static! {
+    /// 已注册的内存堆区域，按注册顺序排列
+    static ref RT_MEMHEAP_LIST: RTIntrFreeCell<Vec<RtMemheap>> = unsafe { RTIntrFreeCell::new(Vec::new()) };
+}
+
+/// 注册一块内存区域为内存堆
+/// @param name 名称
+/// @param tag 区域标签
+/// @param start 区域起始地址
+/// @param size 区域大小
+/// @return RT_EOK: 注册成功
+///         RT_ERROR: 区域太小或与已注册区域重叠
+pub fn rt_memheap_init(name: &str, tag: u8, start: *mut u8, size: usize) -> RtErrT {
+    let begin = start as usize;
+    let end = begin + size;
+    let overlap = RT_MEMHEAP_LIST.exclusive_session(|list| {
+        list.iter().any(|heap| begin < heap.start + heap.size && heap.start < end)
+    });
+    if overlap {
+        return RT_ERROR;
+    }
+
+    let smem = rt_smem_init(name, start, size);
+    if smem.is_null() {
+        return RT_ERROR;
+    }
+    RT_MEMHEAP_LIST.exclusive_access().push(RtMemheap {
+        tag,
+        start: begin,
+        size,
+        smem,
+    });
+    RT_EOK
+}
+
+/// 注册系统内存区域（SRAM 与 CCMRAM），边界来自链接符号
+/// SRAM 区域先注册，不指定标签分配时优先使用
+/// 需在 init_heap 之后调用
+pub fn rt_system_memheap_init() -> RtErrT {
+    let start = &raw mut _ssramheap;
+    let end = &raw mut _esramheap as usize;
+    let result = rt_memheap_init("sramheap", RT_MEMHEAP_TAG_SRAM, start, end - start as usize);
+    if result != RT_EOK {
+        return result;
+    }
+    let start = &raw mut _sccmheap;
+    let end = &raw mut _eccmheap as usize;
+    rt_memheap_init("ccmheap", RT_MEMHEAP_TAG_CCM, start, end - start as usize)
+}
+
+/// 从内存堆分配内存，按注册顺序依次尝试各区域
+/// @param size 大小
+/// @return 内存地址，所有区域都无法满足时返回空指针
+pub fn rt_memheap_alloc(size: usize) -> *mut u8 {
+    RT_MEMHEAP_LIST.exclusive_session(|list| {
+        list.iter()
+            .map(|heap| rt_smem_alloc(heap.smem, size))
+            .find(|ptr| !ptr.is_null())
+            .unwrap_or(ptr::null_mut())
+    })
+}
+
+/// 从指定标签的区域分配内存
+/// @param tag 区域标签
+/// @param size 大小
+/// @return 内存地址，该标签的区域都无法满足时返回空指针
+pub fn rt_memheap_alloc_tag(tag: u8, size: usize) -> *mut u8 {
+    RT_MEMHEAP_LIST.exclusive_session(|list| {
+        list.iter()
+            .filter(|heap| heap.tag == tag)
+            .map(|heap| rt_smem_alloc(heap.smem, size))
+            .find(|ptr| !ptr.is_null())
+            .unwrap_or(ptr::null_mut())
+    })
+}
+
+/// 释放由 rt_memheap_alloc / rt_memheap_alloc_tag 分配的内存
+/// 不属于任何内存堆区域的地址会被忽略
+/// @param ptr 内存地址
+pub fn rt_memheap_free(ptr: *mut u8) {
+    if ptr.is_null() {
+        return;
+    }
+    let addr = ptr as usize;
+    // 与分配一样在会话内释放，避免与其他线程对同一区域的分配交错
+    RT_MEMHEAP_LIST.exclusive_session(|list| {
+        if list.iter().any(|heap| addr >= heap.start && addr < heap.start + heap.size) {
+            rt_smem_free(ptr);
+        }
+    });
+}
+
+/// 打印各内存堆区域的状态信息，用于调试
+pub fn rt_memheap_info() {
+    use cortex_m_semihosting::hprintln;
+
+    let _ = hprintln!("--- Memheap Info ---");
+    RT_MEMHEAP_LIST.exclusive_session(|list| {
+        for heap in list.iter() {
+            unsafe {
+                let _ = hprintln!("tag: {}, address: 0x{:x}, total: {}, used: {}, max used: {}",
+                    heap.tag, heap.start, (*heap.smem).total, (*heap.smem).used, (*heap.smem).max_used);
+            }
+        }
+    });
+    let _ = hprintln!("--------------------");
+}
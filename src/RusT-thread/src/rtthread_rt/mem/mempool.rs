@@ -0,0 +1,203 @@
+//! 内存池模块
+//!
+//! 参考 RT-Thread 的 mempool.c，在调用者提供的内存区域上划分固定大小的内存块，
+//! 分配与释放均为常数时间。内存块耗尽时，申请线程按 IPC 的方式挂起等待。
+//!
+//! 每个内存块前有一个指针大小的块头：
+//! - 空闲时保存下一个空闲块的块头地址（0 表示链表结束）
+//! - 分配后保存所属内存池的地址，rt_mp_free 据此找到内存池
+//!
+//! 结构体：RtMemPool、RtMemPoolInner
+//! 函数：rt_mp_create、rt_mp_delete、rt_mp_alloc、rt_mp_free
+
+#![warn(unused_imports)]
+extern crate alloc;
+
+use core::{mem, ptr};
+use alloc::sync::Arc;
+
+use crate::rtthread_rt::rtdef::{RtErrT, RT_EOK, RT_ERROR, RT_WAITING_NO, RT_ALIGN_SIZE};
+use crate::rtthread_rt::hardware::{rt_hw_interrupt_disable, rt_hw_interrupt_enable};
+use crate::rtthread_rt::kservice::RTIntrFreeCell;
+use crate::rtthread_rt::ipc::{IPCBase, _ipc_init, _ipc_wait, _ipc_list_resume, _ipc_list_resume_all_error, _ipc_remain_timeout};
+use crate::rtthread_rt::rtdef::RT_IPC_FLAG_PRIO;
+use crate::rtthread_rt::thread::{rt_thread_self, rt_schedule};
+use crate::rtthread_rt::timer::rt_tick_get;
+
+/// 内存池对象类型
+pub const RT_OBJECT_CLASS_MEMPOOL: u8 = 9;
+
+/// 块头大小
+const BLOCK_HEADER_SIZE: usize = mem::size_of::<usize>();
+
+/// 内存池结构体
+pub struct RtMemPool {
+    /// 基础 IPC 结构体（等待内存块的线程队列）
+    pub parent: RTIntrFreeCell<Arc<IPCBase>>,
+
+    /// 内存池状态
+    pub inner: RTIntrFreeCell<RtMemPoolInner>,
+}
+
+/// 内存池的可变状态
+pub struct RtMemPoolInner {
+    /// 内存区域起始地址
+    pub start_address: usize,
+    /// 内存区域大小
+    pub size: usize,
+    /// 内存块大小（不含块头）
+    pub block_size: usize,
+    /// 空闲块链表头（块头地址，0 表示没有空闲块）
+    pub block_list: usize,
+    /// 内存块总数
+    pub block_total_count: usize,
+    /// 空闲内存块数
+    pub block_free_count: usize,
+}
+
+/// 向上对齐到 RT_ALIGN_SIZE
+#[inline]
+fn align_up(value: usize) -> usize {
+    (value + RT_ALIGN_SIZE as usize - 1) & !(RT_ALIGN_SIZE as usize - 1)
+}
+
+/// 创建内存池
+/// 内存区域由调用者提供，在内存池删除前不能被其他用途使用
+/// @param name 名称
+/// @param start 内存区域起始地址
+/// @param size 内存区域大小
+/// @param block_size 内存块大小
+/// @return 内存池结构体，参数不合法或区域放不下一个内存块时返回 None
+pub fn rt_mp_create(name: &str, start: *mut u8, size: usize, block_size: usize) -> Option<Arc<RtMemPool>> {
+    if start.is_null() || size == 0 || block_size == 0 {
+        return None;
+    }
+
+    // 对齐起始地址与大小
+    let start_address = align_up(start as usize);
+    let size = (start as usize + size).saturating_sub(start_address) & !(RT_ALIGN_SIZE as usize - 1);
+    let block_size = align_up(block_size);
+    let block_total_count = size / (block_size + BLOCK_HEADER_SIZE);
+    if block_total_count == 0 {
+        return None;
+    }
+
+    // 串起所有空闲块
+    unsafe {
+        for index in 0..block_total_count {
+            let header = start_address + index * (block_size + BLOCK_HEADER_SIZE);
+            let next = if index + 1 < block_total_count {
+                header + block_size + BLOCK_HEADER_SIZE
+            } else {
+                0
+            };
+            ptr::write(header as *mut usize, next);
+        }
+    }
+
+    Some(Arc::new(RtMemPool {
+        parent: unsafe { RTIntrFreeCell::new(_ipc_init(name, RT_OBJECT_CLASS_MEMPOOL, RT_IPC_FLAG_PRIO)) },
+        inner: unsafe {
+            RTIntrFreeCell::new(RtMemPoolInner {
+                start_address,
+                size,
+                block_size,
+                block_list: start_address,
+                block_total_count,
+                block_free_count: block_total_count,
+            })
+        },
+    }))
+}
+
+/// 删除内存池
+/// 唤醒所有等待内存块的线程，其错误码被设置为 RT_ERROR
+/// @param mp 内存池
+/// @return RT_EOK: 删除成功
+pub fn rt_mp_delete(mp: Arc<RtMemPool>) -> RtErrT {
+    let level = rt_hw_interrupt_disable();
+    _ipc_list_resume_all_error(mp.parent.exclusive_session(|ipc| ipc.clone()), RT_ERROR);
+    mp.inner.exclusive_session(|inner| {
+        inner.block_list = 0;
+        inner.block_free_count = 0;
+    });
+    rt_hw_interrupt_enable(level);
+    rt_schedule();
+    RT_EOK
+}
+
+/// 从内存池分配一个内存块
+/// @param mp 内存池
+/// @param timeout 等待时间（tick）：RT_WAITING_NO 不等待，小于0表示永久等待
+/// @return 内存块地址，超时或失败时返回空指针
+pub fn rt_mp_alloc(mp: Arc<RtMemPool>, timeout: i32) -> *mut u8 {
+    let mut timeout = timeout;
+    let mut level = rt_hw_interrupt_disable();
+
+    // 没有空闲块，挂起等待
+    while mp.inner.exclusive_access().block_free_count == 0 {
+        if timeout == RT_WAITING_NO {
+            rt_hw_interrupt_enable(level);
+            return ptr::null_mut();
+        }
+        let thread = match rt_thread_self() {
+            Some(thread) => thread,
+            None => {
+                rt_hw_interrupt_enable(level);
+                return ptr::null_mut();
+            }
+        };
+        let start_tick = rt_tick_get();
+        let error = _ipc_wait(mp.parent.exclusive_session(|ipc| ipc.clone()), thread, timeout, level);
+        if error != RT_EOK {
+            return ptr::null_mut();
+        }
+        timeout = _ipc_remain_timeout(timeout, start_tick);
+        level = rt_hw_interrupt_disable();
+    }
+
+    // 从空闲链表头取出一个块，块头改为指向所属内存池
+    let pool_address = Arc::as_ptr(&mp) as usize;
+    let header = mp.inner.exclusive_session(|inner| {
+        let header = inner.block_list;
+        unsafe {
+            inner.block_list = ptr::read(header as *const usize);
+            ptr::write(header as *mut usize, pool_address);
+        }
+        inner.block_free_count -= 1;
+        header
+    });
+    rt_hw_interrupt_enable(level);
+
+    (header + BLOCK_HEADER_SIZE) as *mut u8
+}
+
+/// 释放内存块
+/// 内存块必须由 rt_mp_alloc 分配，且所属内存池尚未删除
+/// @param block 内存块地址
+pub fn rt_mp_free(block: *mut u8) {
+    if block.is_null() {
+        return;
+    }
+
+    let level = rt_hw_interrupt_disable();
+    let header = block as usize - BLOCK_HEADER_SIZE;
+    let mp = unsafe { &*(ptr::read(header as *const usize) as *const RtMemPool) };
+
+    // 将块放回空闲链表头
+    mp.inner.exclusive_session(|inner| {
+        debug_assert!(header >= inner.start_address && header < inner.start_address + inner.size);
+        unsafe {
+            ptr::write(header as *mut usize, inner.block_list);
+        }
+        inner.block_list = header;
+        inner.block_free_count += 1;
+    });
+
+    // 唤醒一个等待内存块的线程
+    let need_schedule = _ipc_list_resume(mp.parent.exclusive_session(|ipc| ipc.clone())).is_some();
+    rt_hw_interrupt_enable(level);
+    if need_schedule {
+        rt_schedule();
+    }
+}
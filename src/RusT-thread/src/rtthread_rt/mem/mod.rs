@@ -5,18 +5,31 @@
 //! 
 //! - good_memory_allocator: 使用good_memory_allocator分配器
 //! - buddy_system_allocator: 使用buddy_system_allocator分配器
+//! - slab: 使用内置的slab分配器（按大小类别缓存，并提供每个缓存的统计信息）
 //! 
-//! 本模块还提供了与RT-Thread兼容的内存管理API。
+//! 本模块还提供了与RT-Thread兼容的内存管理API，
+//! 固定大小内存块的内存池（mempool），以及跨 RAM/CCMRAM 多区域的内存堆（memheap）。
 
 pub mod allocator;  
 pub mod small_mem_impl;
 pub mod object;
 pub mod small_mem_allocator;
 pub mod safelist;
+pub mod mempool;
+pub mod memheap;
+#[cfg(feature = "slab")]
+pub mod slab;
 
 
 pub use self::small_mem_impl::{rt_smem_init, rt_smem_detach, rt_smem_alloc, rt_smem_realloc, rt_smem_free};
 pub use self::small_mem_allocator::{MemAllocator, rt_mem_info};
 pub use self::object::{rt_object_init, rt_object_detach, OBJECT_REGISTRY};
 pub use self::safelist::{SafeRTList};
-pub use self::allocator::{init_heap};
\ No newline at end of file
+pub use self::allocator::{init_heap};
+pub use self::memheap::{
+    RtMemheap, RT_MEMHEAP_TAG_SRAM, RT_MEMHEAP_TAG_CCM,
+    rt_memheap_init, rt_system_memheap_init, rt_memheap_alloc, rt_memheap_alloc_tag, rt_memheap_free, rt_memheap_info,
+};
+#[cfg(feature = "slab")]
+pub use self::slab::{SlabAllocator, SlabCacheStats, rt_slab_cache_stats, rt_slab_info};
+pub use self::mempool::{RtMemPool, rt_mp_create, rt_mp_delete, rt_mp_alloc, rt_mp_free};
\ No newline at end of file
@@ -0,0 +1,418 @@
+//! slab 内存分配器
+//!
+//! 参考 RT-Thread 的 slab.c 实现，作为 `slab` 特性下的全局分配器：
+//! - 堆被划分为 SLAB_PAGE_SIZE 大小的页，每页的使用情况记录在堆开头的 memusage 数组中
+//! - 不超过 SLAB_ZONE_LIMIT 的请求按大小类别（size class）由对应的缓存（cache）分配，
+//!   每个缓存由若干 zone 组成，一个 zone 占一页，被切分成相同大小的块（chunk）
+//! - 更大的请求直接分配连续的页（large）
+//!
+//! 分配和释放小块都是常数时间：zone 中的空闲块组成单链表，
+//! 从未使用过的块按 uindex 顺序切出，不需要初始化整个 zone。
+//!
+//! 结构体：SlabAllocator、SlabCacheStats
+//! 函数：rt_slab_cache_stats、rt_slab_info
+
+#![warn(unused_imports)]
+
+use core::alloc::{GlobalAlloc, Layout};
+use core::{mem, ptr};
+use spin::Mutex;
+
+use crate::rtthread_rt::hardware::{rt_hw_interrupt_disable, rt_hw_interrupt_enable};
+
+/// 页大小
+pub const SLAB_PAGE_SIZE: usize = 1024;
+
+/// 由缓存管理的最大分配大小，超过后直接分配页
+pub const SLAB_ZONE_LIMIT: usize = 512;
+
+/// 整页空闲的 zone 超过该数量时，将空闲 zone 归还给页分配器
+const ZONE_RELEASE_THRESH: usize = 2;
+
+/// 大小类别，每个类别对应一个缓存
+const SLAB_SIZE_CLASSES: [usize; SLAB_NCACHES] = [8, 16, 32, 48, 64, 96, 128, 192, 256, 384, 512];
+
+/// 缓存数量
+pub const SLAB_NCACHES: usize = 11;
+
+/// 页类型
+const PAGE_TYPE_FREE: u8 = 0x00;
+const PAGE_TYPE_SMALL: u8 = 0x01;
+const PAGE_TYPE_LARGE: u8 = 0x02;
+
+/// 空链表
+const ZONE_NONE: usize = usize::MAX;
+
+/// 每页的使用情况
+#[derive(Clone, Copy)]
+struct SlabMemUsage {
+    /// 页类型
+    page_type: u8,
+    /// SMALL：所属缓存下标
+    cache: u8,
+    /// LARGE：首页记录连续页数，其余页为 0
+    size: usize,
+    /// SMALL：空闲块链表头（0 表示没有）
+    zone_free: usize,
+    /// SMALL：空闲块数（包括未切出的块）
+    zone_nfree: usize,
+    /// SMALL：下一个未切出的块下标
+    zone_uindex: usize,
+    /// SMALL：缓存中下一个有空闲块的 zone（页下标）
+    zone_next: usize,
+}
+
+/// 单个缓存的统计信息
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SlabCacheStats {
+    /// 块大小
+    pub size: usize,
+    /// zone 数量
+    pub zones: usize,
+    /// 块总数
+    pub total: usize,
+    /// 已使用块数
+    pub used: usize,
+    /// 最大已使用块数
+    pub max_used: usize,
+    /// 累计分配次数
+    pub alloc_count: usize,
+    /// 累计释放次数
+    pub free_count: usize,
+}
+
+/// 缓存
+struct SlabCache {
+    /// 有空闲块的 zone 链表（页下标）
+    zone_list: usize,
+    /// 统计信息
+    stats: SlabCacheStats,
+}
+
+/// slab 堆
+struct SlabHeap {
+    /// 页使用情况数组
+    memusage: *mut SlabMemUsage,
+    /// 第一页的地址
+    base: usize,
+    /// 页数
+    npages: usize,
+    /// 空闲页数
+    free_pages: usize,
+    /// 各大小类别的缓存
+    caches: [SlabCache; SLAB_NCACHES],
+}
+
+// 堆只在关中断并持有锁时访问
+unsafe impl Send for SlabHeap {}
+
+/// 块大小的自然对齐（最低的置位）
+#[inline]
+fn class_align(size: usize) -> usize {
+    size & size.wrapping_neg()
+}
+
+/// 每个 zone 的块数
+#[inline]
+fn zone_chunks(cache: usize) -> usize {
+    SLAB_PAGE_SIZE / SLAB_SIZE_CLASSES[cache]
+}
+
+/// 选择能满足大小和对齐要求的缓存
+fn cache_index(layout: &Layout) -> Option<usize> {
+    SLAB_SIZE_CLASSES.iter().position(|&size| {
+        size >= layout.size() && class_align(size) >= layout.align()
+    })
+}
+
+impl SlabHeap {
+    /// 在 [start, start + size) 上建立 slab 堆
+    unsafe fn new(start: usize, size: usize) -> Option<Self> {
+        let usage_size = mem::size_of::<SlabMemUsage>();
+        let memusage = (start + mem::align_of::<SlabMemUsage>() - 1) & !(mem::align_of::<SlabMemUsage>() - 1);
+        let end = start + size;
+        if end <= memusage {
+            return None;
+        }
+        // 先按最大可能页数预留 memusage，再根据对齐后的页起始地址修正页数
+        let npages = (end - memusage) / (SLAB_PAGE_SIZE + usage_size);
+        let base = (memusage + npages * usage_size + SLAB_PAGE_SIZE - 1) & !(SLAB_PAGE_SIZE - 1);
+        if end <= base {
+            return None;
+        }
+        let npages = npages.min((end - base) / SLAB_PAGE_SIZE);
+        if npages == 0 {
+            return None;
+        }
+
+        let memusage = memusage as *mut SlabMemUsage;
+        for index in 0..npages {
+            unsafe {
+                ptr::write(memusage.add(index), SlabMemUsage {
+                    page_type: PAGE_TYPE_FREE,
+                    cache: 0,
+                    size: 0,
+                    zone_free: 0,
+                    zone_nfree: 0,
+                    zone_uindex: 0,
+                    zone_next: ZONE_NONE,
+                });
+            }
+        }
+
+        Some(Self {
+            memusage,
+            base,
+            npages,
+            free_pages: npages,
+            caches: core::array::from_fn(|index| SlabCache {
+                zone_list: ZONE_NONE,
+                stats: SlabCacheStats {
+                    size: SLAB_SIZE_CLASSES[index],
+                    ..SlabCacheStats::default()
+                },
+            }),
+        })
+    }
+
+    /// 获取页的使用情况
+    #[inline]
+    fn usage(&self, page: usize) -> &'static mut SlabMemUsage {
+        unsafe { &mut *self.memusage.add(page) }
+    }
+
+    /// 分配连续的页，首页地址按 align 对齐
+    /// @return 首页下标
+    fn page_alloc(&mut self, npages: usize, align: usize) -> Option<usize> {
+        let mut page = 0;
+        while page + npages <= self.npages {
+            if (self.base + page * SLAB_PAGE_SIZE) % align != 0 {
+                page += 1;
+                continue;
+            }
+            match (page..page + npages).find(|&index| self.usage(index).page_type != PAGE_TYPE_FREE) {
+                Some(used) => page = used + 1,
+                None => {
+                    for index in page..page + npages {
+                        self.usage(index).page_type = PAGE_TYPE_LARGE;
+                        self.usage(index).size = 0;
+                    }
+                    self.usage(page).size = npages;
+                    self.free_pages -= npages;
+                    return Some(page);
+                }
+            }
+        }
+        None
+    }
+
+    /// 释放连续的页
+    fn page_free(&mut self, page: usize, npages: usize) {
+        for index in page..page + npages {
+            let usage = self.usage(index);
+            usage.page_type = PAGE_TYPE_FREE;
+            usage.size = 0;
+        }
+        self.free_pages += npages;
+    }
+
+    /// 从缓存分配一个块
+    fn alloc_small(&mut self, cache: usize) -> *mut u8 {
+        let size = SLAB_SIZE_CLASSES[cache];
+        let mut zone = self.caches[cache].zone_list;
+        if zone == ZONE_NONE {
+            // 没有可用的 zone，分配一页作为新的 zone
+            zone = match self.page_alloc(1, SLAB_PAGE_SIZE) {
+                Some(page) => page,
+                None => return ptr::null_mut(),
+            };
+            *self.usage(zone) = SlabMemUsage {
+                page_type: PAGE_TYPE_SMALL,
+                cache: cache as u8,
+                size: 1,
+                zone_free: 0,
+                zone_nfree: zone_chunks(cache),
+                zone_uindex: 0,
+                zone_next: ZONE_NONE,
+            };
+            let stats = &mut self.caches[cache].stats;
+            stats.zones += 1;
+            stats.total += zone_chunks(cache);
+            self.caches[cache].zone_list = zone;
+        }
+
+        let usage = self.usage(zone);
+        let chunk = if usage.zone_free != 0 {
+            let chunk = usage.zone_free;
+            usage.zone_free = unsafe { ptr::read(chunk as *const usize) };
+            chunk
+        } else {
+            let chunk = self.base + zone * SLAB_PAGE_SIZE + usage.zone_uindex * size;
+            usage.zone_uindex += 1;
+            chunk
+        };
+        usage.zone_nfree -= 1;
+        if usage.zone_nfree == 0 {
+            // zone 已满，从缓存的可用链表中移除
+            self.caches[cache].zone_list = usage.zone_next;
+            usage.zone_next = ZONE_NONE;
+        }
+
+        let stats = &mut self.caches[cache].stats;
+        stats.used += 1;
+        stats.alloc_count += 1;
+        if stats.used > stats.max_used {
+            stats.max_used = stats.used;
+        }
+        chunk as *mut u8
+    }
+
+    /// 将块归还给缓存
+    fn free_small(&mut self, zone: usize, chunk: usize) {
+        let usage = self.usage(zone);
+        let cache = usage.cache as usize;
+        unsafe {
+            ptr::write(chunk as *mut usize, usage.zone_free);
+        }
+        usage.zone_free = chunk;
+        usage.zone_nfree += 1;
+        if usage.zone_nfree == 1 {
+            // zone 由满变为有空闲块，重新挂到缓存的可用链表
+            usage.zone_next = self.caches[cache].zone_list;
+            self.caches[cache].zone_list = zone;
+        }
+
+        let stats = &mut self.caches[cache].stats;
+        stats.used -= 1;
+        stats.free_count += 1;
+
+        // zone 整页空闲且缓存中 zone 较多时，归还该页
+        if usage.zone_nfree == zone_chunks(cache) && stats.zones > ZONE_RELEASE_THRESH {
+            let mut link = self.caches[cache].zone_list;
+            if link == zone {
+                self.caches[cache].zone_list = usage.zone_next;
+            } else {
+                while link != ZONE_NONE {
+                    let prev = self.usage(link);
+                    if prev.zone_next == zone {
+                        prev.zone_next = usage.zone_next;
+                        break;
+                    }
+                    link = prev.zone_next;
+                }
+            }
+            let stats = &mut self.caches[cache].stats;
+            stats.zones -= 1;
+            stats.total -= zone_chunks(cache);
+            self.page_free(zone, 1);
+        }
+    }
+
+    /// 分配内存
+    fn alloc(&mut self, layout: Layout) -> *mut u8 {
+        if let Some(cache) = cache_index(&layout) {
+            return self.alloc_small(cache);
+        }
+        let npages = layout.size().div_ceil(SLAB_PAGE_SIZE);
+        match self.page_alloc(npages, layout.align().max(SLAB_PAGE_SIZE)) {
+            Some(page) => (self.base + page * SLAB_PAGE_SIZE) as *mut u8,
+            None => ptr::null_mut(),
+        }
+    }
+
+    /// 释放内存
+    fn dealloc(&mut self, addr: *mut u8) {
+        let addr = addr as usize;
+        if addr < self.base || addr >= self.base + self.npages * SLAB_PAGE_SIZE {
+            return;
+        }
+        let page = (addr - self.base) / SLAB_PAGE_SIZE;
+        match self.usage(page).page_type {
+            PAGE_TYPE_SMALL => self.free_small(page, addr),
+            PAGE_TYPE_LARGE => {
+                let npages = self.usage(page).size;
+                debug_assert!(npages != 0, "slab: free of a non-head large page");
+                self.page_free(page, npages);
+            }
+            _ => debug_assert!(false, "slab: double free"),
+        }
+    }
+}
+
+/// slab 全局分配器
+pub struct SlabAllocator {
+    heap: Mutex<Option<SlabHeap>>,
+}
+
+impl SlabAllocator {
+    /// 创建一个空的分配器，需调用 init 后才能分配
+    pub const fn empty() -> Self {
+        Self {
+            heap: Mutex::new(None),
+        }
+    }
+
+    /// 初始化分配器
+    /// @param start 堆起始地址
+    /// @param size 堆大小
+    pub unsafe fn init(&self, start: usize, size: usize) {
+        let level = rt_hw_interrupt_disable();
+        *self.heap.lock() = unsafe { SlabHeap::new(start, size) };
+        rt_hw_interrupt_enable(level);
+    }
+
+    /// 获取各缓存的统计信息
+    pub fn cache_stats(&self) -> [SlabCacheStats; SLAB_NCACHES] {
+        let level = rt_hw_interrupt_disable();
+        let stats = self.heap.lock().as_ref().map_or([SlabCacheStats::default(); SLAB_NCACHES], |heap| {
+            core::array::from_fn(|index| heap.caches[index].stats)
+        });
+        rt_hw_interrupt_enable(level);
+        stats
+    }
+
+    /// 获取空闲页数与总页数
+    pub fn page_stats(&self) -> (usize, usize) {
+        let level = rt_hw_interrupt_disable();
+        let stats = self.heap.lock().as_ref().map_or((0, 0), |heap| (heap.free_pages, heap.npages));
+        rt_hw_interrupt_enable(level);
+        stats
+    }
+}
+
+unsafe impl GlobalAlloc for SlabAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let level = rt_hw_interrupt_disable();
+        let ptr = self.heap.lock().as_mut().map_or(ptr::null_mut(), |heap| heap.alloc(layout));
+        rt_hw_interrupt_enable(level);
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, _layout: Layout) {
+        let level = rt_hw_interrupt_disable();
+        if let Some(heap) = self.heap.lock().as_mut() {
+            heap.dealloc(ptr);
+        }
+        rt_hw_interrupt_enable(level);
+    }
+}
+
+/// 获取全局 slab 分配器各缓存的统计信息
+pub fn rt_slab_cache_stats() -> [SlabCacheStats; SLAB_NCACHES] {
+    super::allocator::SLAB_ALLOCATOR.cache_stats()
+}
+
+/// 打印全局 slab 分配器的状态信息，用于调试
+pub fn rt_slab_info() {
+    use cortex_m_semihosting::hprintln;
+
+    let (free_pages, npages) = super::allocator::SLAB_ALLOCATOR.page_stats();
+    let _ = hprintln!("--- Slab Info ---");
+    let _ = hprintln!("Pages: {} free / {} total ({} bytes each)", free_pages, npages, SLAB_PAGE_SIZE);
+    let _ = hprintln!("size   zones  total  used   max    allocs  frees");
+    for stats in rt_slab_cache_stats().iter() {
+        let _ = hprintln!("{:<6} {:<6} {:<6} {:<6} {:<6} {:<7} {}",
+            stats.size, stats.zones, stats.total, stats.used, stats.max_used, stats.alloc_count, stats.free_count);
+    }
+    let _ = hprintln!("-----------------");
+}
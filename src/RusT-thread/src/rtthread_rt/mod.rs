@@ -10,4 +10,5 @@ pub mod rtdef;
 pub mod rtconfig;
 pub mod thread;
 pub mod timer;
-pub mod ipc;
\ No newline at end of file
+pub mod ipc;
+pub mod api;
\ No newline at end of file
@@ -24,6 +24,9 @@ pub const RT_NAME_MAX: usize = 16;
 /// 内核栈大小
 pub const KERNEL_STACK_SIZE: usize = 0x400;//1kB
 
+/// 线程栈的最小大小（需容纳栈底保护区与初始栈帧）
+pub const RT_THREAD_STACK_SIZE_MIN: usize = 256;
+
 /// 用户主线程优先级
 pub const RT_MAIN_THREAD_PRIORITY: u32 = 16;
 
@@ -33,18 +36,29 @@ pub const RT_MAIN_THREAD_STACK_SIZE: u32 = 4*1024;
 /// 调试
 pub const RT_DEBUG: bool = false;
 pub const RT_DEBUG_INIT: u32 = 0;
-pub const RT_USING_OVERFLOW_CHECK: bool = false;
+/// 线程栈溢出检查：每次线程切换时检查栈底保护区与栈指针
+pub const RT_USING_OVERFLOW_CHECK: bool = true;
+/// 使用 MPU 保护当前线程的栈底（需同时开启 RT_USING_OVERFLOW_CHECK）
+pub const RT_USING_MPU_STACK_GUARD: bool = false;
 pub const RT_USING_HOOK: bool = false;
 pub const RT_USING_IDLE_HOOK: bool = false;
-pub const RT_USING_TIMER_SOFT: bool = false;
+/// tickless 空闲：空闲时按最近的定时器超时设置 SysTick 并睡眠（wfi）
+pub const RT_USING_TICKLESS: bool = false;
+/// 软定时器：带 RT_TIMER_FLAG_SOFT_TIMER 的定时器由定时器线程执行回调
+pub const RT_USING_TIMER_SOFT: bool = true;
 pub const RT_TIMER_THREAD_PRIO: u32 = 4;
-pub const RT_TIMER_THREAD_STACK_SIZE: u32 = 512;
+/// 定时器线程栈大小，软定时器回调运行在该栈上
+pub const RT_TIMER_THREAD_STACK_SIZE: u32 = 1024;
+/// 定时器跳表层数
+pub const RT_TIMER_SKIP_LIST_LEVEL: usize = 4;
+/// 定时器跳表升层掩码：随机数的低位全为 0 时升一层，即每层概率为 1/4
+pub const RT_TIMER_SKIP_LIST_MASK: u32 = 0x3;
 pub const RT_USING_SEMAPHORE: bool = true;
-pub const RT_USING_MUTEX: bool = false;
-pub const RT_USING_EVENT: bool = false;
-pub const RT_USING_SIGNALS: bool = false;
+pub const RT_USING_MUTEX: bool = true;
+pub const RT_USING_EVENT: bool = true;
+pub const RT_USING_SIGNALS: bool = true;
 pub const RT_USING_MAILBOX: bool = true;
-pub const RT_USING_MESSAGEQUEUE: bool = false;
+pub const RT_USING_MESSAGEQUEUE: bool = true;
 pub const RT_USING_HEAP: bool = true;
 pub const RT_USING_SMALL_MEM: bool = true;
 pub const RT_USING_TINY_SIZE: bool = false;
@@ -164,6 +164,7 @@ pub const RT_EIO: RtErrT = 8;
 pub const RT_EINTR: RtErrT = 9;
 pub const RT_EINVAL: RtErrT = 10;
 /// Error type for RT-Thread operations
+/// 与 RT_E* 错误码一一对应，可通过 code / from_code 互相转换
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum RtError {
     Ok,
@@ -179,11 +180,92 @@ pub enum RtError {
     InvalidArgument,
 }
 
+impl RtError {
+    /// 转换为 C 风格的错误码（RT_E*）
+    pub fn code(self) -> RtErrT {
+        match self {
+            RtError::Ok => RT_EOK,
+            RtError::Error => RT_ERROR,
+            RtError::Timeout => RT_ETIMEOUT,
+            RtError::Full => RT_EFULL,
+            RtError::Empty => RT_EEMPTY,
+            RtError::NoMemory => RT_ENOMEM,
+            RtError::NoSystem => RT_ENOSYS,
+            RtError::Busy => RT_EBUSY,
+            RtError::IoError => RT_EIO,
+            RtError::Interrupted => RT_EINTR,
+            RtError::InvalidArgument => RT_EINVAL,
+        }
+    }
+
+    /// 由 C 风格的错误码转换
+    /// 只接受已定义的 RT_E* 错误码，其余取值（包括取负的错误码）一律视为 RtError::Error
+    pub fn from_code(code: RtErrT) -> Self {
+        match code {
+            RT_EOK => RtError::Ok,
+            RT_ERROR => RtError::Error,
+            RT_ETIMEOUT => RtError::Timeout,
+            RT_EFULL => RtError::Full,
+            RT_EEMPTY => RtError::Empty,
+            RT_ENOMEM => RtError::NoMemory,
+            RT_ENOSYS => RtError::NoSystem,
+            RT_EBUSY => RtError::Busy,
+            RT_EIO => RtError::IoError,
+            RT_EINTR => RtError::Interrupted,
+            RT_EINVAL => RtError::InvalidArgument,
+            _ => RtError::Error,
+        }
+    }
+}
+
+impl From<RtError> for RtErrT {
+    fn from(error: RtError) -> Self {
+        error.code()
+    }
+}
+
+impl From<RtErrT> for RtError {
+    fn from(code: RtErrT) -> Self {
+        RtError::from_code(code)
+    }
+}
+
+/// 将 C 风格的错误码转换为 Result
+/// @param code 错误码
+/// @return RT_EOK 时为 Ok(())，否则为对应的 RtError
+pub fn rt_result(code: RtErrT) -> Result<(), RtError> {
+    if code == RT_EOK {
+        Ok(())
+    } else {
+        Err(RtError::from_code(code))
+    }
+}
+
+/// 将 Result 转换为 C 风格的错误码
+/// @param result 结果
+/// @return Ok 时为 RT_EOK，否则为对应的错误码
+pub fn rt_result_code<T>(result: &Result<T, RtError>) -> RtErrT {
+    match result {
+        Ok(_) => RT_EOK,
+        Err(error) => error.code(),
+    }
+}
+
 
 /// IPC flags
 pub const RT_IPC_FLAG_FIFO: u8 = 0x00;
 pub const RT_IPC_FLAG_PRIO: u8 = 0x01;
 
+/// Event flags
+pub const RT_EVENT_FLAG_AND: u8 = 0x01;
+pub const RT_EVENT_FLAG_OR: u8 = 0x02;
+pub const RT_EVENT_FLAG_CLEAR: u8 = 0x04;
+
+/// Signal definitions
+pub const RT_SIG_MAX: i32 = 32;
+/// 信号由 rt_thread_kill 发出
+pub const SI_USER: i32 = 0x01;
+
 /// IPC control commands
 pub const RT_IPC_CMD_UNKNOWN: u8 = 0x00;
 pub const RT_IPC_CMD_RESET: u8 = 0x01;
@@ -215,17 +297,22 @@ pub struct RtObject {
 }
 
 impl RtObject {
-    /// 创建一个新的RtObject实例
-    pub fn new(name: &str, obj_type: u8, flag: u8) -> &'static Self {
+    /// 初始化一个RtObject实例，不分配内存，也不加入对象列表
+    pub fn init(name: &str, obj_type: u8, flag: u8) -> Self {
         let mut name_buf = [0u8; rtconfig::RT_NAME_MAX];
         let name_bytes = name.as_bytes();
         let len = name_bytes.len().min(rtconfig::RT_NAME_MAX);
         name_buf[..len].copy_from_slice(&name_bytes[..len]);
-        let obj = Box::leak(Box::new(Self {
+        Self {
             name: name_buf,
             obj_type,
             flag,
-        }));
+        }
+    }
+
+    /// 创建一个新的RtObject实例
+    pub fn new(name: &str, obj_type: u8, flag: u8) -> &'static Self {
+        let obj = Box::leak(Box::new(Self::init(name, obj_type, flag)));
         RT_OBJECT_LIST.lock().push(obj);
         obj
     }
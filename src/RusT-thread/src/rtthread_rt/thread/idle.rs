@@ -11,10 +11,11 @@ use crate::rtthread_rt::thread::*;
 use crate::rtthread_rt::rtconfig;
 use crate::rtthread_rt::thread::thread_priority_table;
 use crate::rtthread_rt::rtdef::ThreadState;
-use crate::rtthread_rt::timer::rt_tick_get;
+use crate::rtthread_rt::timer::{rt_tick_get, rt_tickless_idle};
 
 /// 空闲线程入口函数
-/// 用户可以在这里实现自己的空闲线程逻辑（默认是空循环）
+/// 回收已删除或已退出的线程，其余时间空转（开启 RT_USING_TICKLESS 时进入 tickless 睡眠）
+/// 用户可以在这里实现自己的空闲线程逻辑
 pub extern "C" fn idle_entry(arg: usize) -> () {
     hprintln!("idle_entry...");
     // let tic = rt_tick_get();
@@ -27,7 +28,12 @@ pub extern "C" fn idle_entry(arg: usize) -> () {
         //     hprintln!("idle_entry...");
         //     start_tick = rt_tick_get();
         // }
-        asm::nop();
+        rt_thread_defunct_process();
+        if rtconfig::RT_USING_TICKLESS {
+            rt_tickless_idle();
+        } else {
+            asm::nop();
+        }
     }
     hprintln!("idle_entry finished.");
 }
@@ -35,7 +41,7 @@ pub extern "C" fn idle_entry(arg: usize) -> () {
 /// 即创建一个空闲线程，并将其插入到就绪队列中
 pub fn init_idle(){
     // hprintln!("Initializing idle...");
-    let idle = rt_thread_create("idle", idle_entry as usize, 1024, (rtconfig::RT_THREAD_PRIORITY_MAX - 1) , 100);
+    let idle = rt_thread_create("idle", idle_entry as usize, 0, 1024, (rtconfig::RT_THREAD_PRIORITY_MAX - 1) , 100).unwrap();
     idle.inner.exclusive_access().stat = ThreadState::Ready;
     thread_priority_table::insert_thread(idle.clone());
     // hprintln!("Idle initialized.");
@@ -1,6 +1,14 @@
 //! 内核栈
 //! 
-//! 定义了内核栈的结构体和相关函数
+//! 定义了内核栈的结构体和相关函数，以及线程栈溢出检查
+//!
+//! 栈在创建时整体填充为 RT_STACK_FILL，栈底的 RT_STACK_GUARD_SIZE 字节作为保护区：
+//! 调度器每次切换线程时检查保护区是否被改写、栈指针是否越界（RT_USING_OVERFLOW_CHECK）；
+//! 若开启 RT_USING_MPU_STACK_GUARD，还会用 MPU 把当前线程的保护区设为只读，
+//! 溢出写入时立即触发 MemoryManagement 异常。异常处理中可通过 rt_stack_overflow_thread 获取溢出的线程。
+//!
+//! 结构体：KernelStack
+//! 函数：rt_system_stack_guard_init、rt_stack_guard_switch、rt_stack_check、rt_stack_overflow_thread
 
 #![warn(unused_imports)]
 
@@ -18,6 +26,8 @@ use crate::rtthread_rt::timer::*;
 use crate::rtthread_rt::rtconfig::*;
 
 use core::fmt::Debug;
+use core::ptr;
+use core::sync::atomic::{AtomicBool, AtomicPtr, AtomicUsize, Ordering};
 use alloc::sync::Arc;
 use alloc::alloc::{
     alloc,
@@ -26,6 +36,12 @@ use alloc::alloc::{
 };
 use cortex_m_semihosting::hprintln;
 
+/// 栈填充字节，创建栈时用它填满整个栈
+pub const RT_STACK_FILL: u8 = b'#';
+
+/// 栈底保护区大小（字节）
+/// 需为 2 的幂且不小于 32（MPU 区域的最小大小）
+pub const RT_STACK_GUARD_SIZE: usize = 32;
 
 /// 内核栈结构体
 /// 注意：内核栈的地址是向下增长的，即栈底在高地址（更大），栈顶在低地址（更小）
@@ -34,6 +50,8 @@ pub struct KernelStack {
     bottom: usize,
     /// 内核栈的大小，单位是字节
     size: usize,
+    /// 栈内存是否由本结构体从堆上分配（静态栈不需要释放）
+    owned: bool,
 }
 
 impl KernelStack {
@@ -51,17 +69,62 @@ impl KernelStack {
     pub fn new(size: usize) -> Self {
         //! 注意：若size 过小时（如200）线程极容易溢出栈引发错误！。
         //! size = 1024可保证正常工作（但大量递归调用仍会溢出）。
+        Self::try_new(size).expect("KernelStack::new: invalid size or out of memory")
+    }
+
+    /// 创建一个新的内核栈，失败时返回错误而不是 panic
+    /// 栈按自身大小对齐分配，因此大小需为 2 的幂
+    /// @param size 内核栈的大小，单位是字节
+    /// @return Ok: 内核栈
+    ///         Err(RtError::InvalidArgument): 大小为0或不是 2 的幂
+    ///         Err(RtError::NoMemory): 内存分配失败
+    pub fn try_new(size: usize) -> Result<Self, RtError> {
         // hprintln!("KernelStack::new: enter");
-        let bottom = unsafe {
-            alloc(Layout::from_size_align(size, size).unwrap()) as usize
-        };
+        let layout = Layout::from_size_align(size, size).map_err(|_| RtError::InvalidArgument)?;
+        if size == 0 {
+            return Err(RtError::InvalidArgument);
+        }
+        let bottom = unsafe { alloc(layout) as usize };
+        if bottom == 0 {
+            return Err(RtError::NoMemory);
+        }
+        // 填满整个栈，用于溢出检查与栈使用量统计
+        unsafe {
+            ptr::write_bytes(bottom as *mut u8, RT_STACK_FILL, size);
+        }
         // hprintln!("KernelStack::new: bottom: {}", bottom);
-        KernelStack { bottom, size }
+        Ok(KernelStack { bottom, size, owned: true })
+    }
+
+    /// 使用静态缓冲区作为内核栈（不从堆上分配）
+    /// 栈底会向上对齐到 RT_STACK_GUARD_SIZE，以便设置 MPU 保护区；
+    /// 栈顶向下对齐到 8 字节，rt_hw_stack_init 按 8 字节对齐栈顶时不会越过缓冲区
+    /// @param buffer 静态栈缓冲区
+    pub fn from_static(buffer: &'static mut [u8]) -> Self {
+        let (bottom, size) = Self::static_range(buffer);
+        unsafe {
+            ptr::write_bytes(bottom as *mut u8, RT_STACK_FILL, size);
+        }
+        KernelStack { bottom, size, owned: false }
+    }
+
+    /// 静态缓冲区对齐后可用作栈的大小，不接管缓冲区
+    /// @param buffer 静态栈缓冲区
+    pub fn static_size(buffer: &[u8]) -> usize {
+        Self::static_range(buffer).1
+    }
+
+    /// 静态缓冲区对齐后的栈底地址与大小
+    fn static_range(buffer: &[u8]) -> (usize, usize) {
+        let start = buffer.as_ptr() as usize;
+        let end = (start + buffer.len()) & !7;
+        let bottom = (start + RT_STACK_GUARD_SIZE - 1) & !(RT_STACK_GUARD_SIZE - 1);
+        (bottom, end.saturating_sub(bottom))
     }
 
     /// 创建一个空的内核栈
     pub fn new_empty() -> Self {
-        KernelStack { bottom: 0, size: 0 }
+        KernelStack { bottom: 0, size: 0, owned: false }
     }
 
     /// 获取内核栈的大小
@@ -79,12 +142,44 @@ impl KernelStack {
         self.bottom + self.size
     }
 
+    /// 栈底保护区是否完好（仍为填充字节）
+    pub fn guard_intact(&self) -> bool {
+        let guard = unsafe {
+            core::slice::from_raw_parts(self.bottom as *const u8, RT_STACK_GUARD_SIZE.min(self.size))
+        };
+        guard.iter().all(|&byte| byte == RT_STACK_FILL)
+    }
+
+    /// 栈的最大使用量（高水位）
+    /// 从栈底向上扫描第一个被改写的填充字节
+    /// @return 曾经使用过的最大字节数
+    pub fn max_used(&self) -> usize {
+        if self.bottom == 0 {
+            return 0;
+        }
+        let stack = unsafe {
+            core::slice::from_raw_parts(self.bottom as *const u8, self.size)
+        };
+        let unused = stack.iter().position(|&byte| byte != RT_STACK_FILL).unwrap_or(self.size);
+        self.size - unused
+    }
+
+    /// 检查栈是否溢出
+    /// @param sp 线程保存的栈指针
+    /// @return true: 保护区被改写或栈指针越出栈的可用范围
+    pub fn is_overflow(&self, sp: usize) -> bool {
+        if self.bottom == 0 {
+            return false;
+        }
+        !self.guard_intact() || sp < self.bottom + RT_STACK_GUARD_SIZE || sp > self.top()
+    }
+
 }
 
 /// 内核栈的析构函数
 impl Drop for KernelStack {
     fn drop(&mut self) {
-        if self.bottom != 0 {
+        if self.bottom != 0 && self.owned {
             unsafe {
                 dealloc(
                     self.bottom as _,
@@ -93,4 +188,82 @@ impl Drop for KernelStack {
             }
         }
     }
-}
\ No newline at end of file
+}
+
+/// MPU 栈保护是否已使能
+static STACK_GUARD_MPU: AtomicBool = AtomicBool::new(false);
+/// 当前设置了保护区的线程
+static STACK_GUARD_THREAD: AtomicPtr<RtThread> = AtomicPtr::new(ptr::null_mut());
+/// 当前保护区的起始地址
+static STACK_GUARD_ADDR: AtomicUsize = AtomicUsize::new(0);
+/// 栈检查发现溢出的线程
+static STACK_OVERFLOW_THREAD: AtomicPtr<RtThread> = AtomicPtr::new(ptr::null_mut());
+
+/// 初始化栈溢出检查，在调度器启动前调用
+/// 使能 MemoryManagement 异常，并按配置使能 MPU 栈保护
+pub fn rt_system_stack_guard_init() {
+    if !RT_USING_OVERFLOW_CHECK {
+        return;
+    }
+    let mpu = rt_hw_mpu_init(RT_USING_MPU_STACK_GUARD);
+    STACK_GUARD_MPU.store(mpu, Ordering::SeqCst);
+}
+
+/// 切换到线程前调用：把 MPU 保护区移到该线程的栈底
+/// @param thread 即将运行的线程
+pub fn rt_stack_guard_switch(thread: &Arc<RtThread>) {
+    if !STACK_GUARD_MPU.load(Ordering::SeqCst) {
+        return;
+    }
+    let bottom = thread.inner.exclusive_access().kernel_stack.bottom();
+    if bottom == 0 {
+        return;
+    }
+    STACK_GUARD_THREAD.store(Arc::as_ptr(thread) as *mut RtThread, Ordering::SeqCst);
+    STACK_GUARD_ADDR.store(bottom, Ordering::SeqCst);
+    rt_hw_stack_guard_set(bottom, RT_STACK_GUARD_SIZE);
+}
+
+/// 检查线程栈是否溢出，溢出时触发 MemoryManagement 异常（不再返回）
+/// @param thread 线程对象
+/// @param check_sp 是否检查线程保存的栈指针（仅在栈指针已保存时有意义）
+pub fn rt_stack_check(thread: &Arc<RtThread>, check_sp: bool) {
+    let overflow = thread.inner.exclusive_session(|inner| {
+        let sp = if check_sp { inner.stack_pointer as usize } else { inner.kernel_stack.top() };
+        inner.kernel_stack.is_overflow(sp)
+    });
+    if !overflow {
+        return;
+    }
+    STACK_OVERFLOW_THREAD.store(Arc::as_ptr(thread) as *mut RtThread, Ordering::SeqCst);
+    rt_hw_mem_fault_pend();
+    // 开中断后立即进入 MemoryManagement 异常
+    unsafe {
+        cortex_m::interrupt::enable();
+    }
+    loop {}
+}
+
+/// 获取栈溢出的线程，供 MemoryManagement 异常处理使用
+/// @param fault_addr 访问出错的地址，None 表示地址未知（如异常入栈时出错）
+/// @return 栈溢出的线程，不是栈溢出引起的异常时返回 None
+pub fn rt_stack_overflow_thread(fault_addr: Option<usize>) -> Option<&'static RtThread> {
+    let thread = STACK_OVERFLOW_THREAD.load(Ordering::SeqCst);
+    if !thread.is_null() {
+        return Some(unsafe { &*thread });
+    }
+    if !STACK_GUARD_MPU.load(Ordering::SeqCst) {
+        return None;
+    }
+    let guard = STACK_GUARD_ADDR.load(Ordering::SeqCst);
+    let in_guard = match fault_addr {
+        Some(addr) => addr >= guard && addr < guard + RT_STACK_GUARD_SIZE,
+        None => true,
+    };
+    let thread = STACK_GUARD_THREAD.load(Ordering::SeqCst);
+    if in_guard && !thread.is_null() {
+        Some(unsafe { &*thread })
+    } else {
+        None
+    }
+}
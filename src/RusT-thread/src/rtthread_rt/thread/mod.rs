@@ -8,6 +8,7 @@ pub mod idle;
 pub mod thread_priority_table;
 pub mod kstack;
 pub mod scheduling_policy;
+pub mod signal;
 
 // 重新导出所有公共项
 pub use self::scheduler::{
@@ -34,7 +35,13 @@ pub use self::thread::{
     RtThread,
     RtThreadInner,
     RtContext,
+    RtThreadStorage,
+    RT_THREAD_STORAGE_SIZE,
     rt_thread_create, 
+    rt_thread_init,
+    rt_thread_spawn,
+    rt_thread_exit,
+    rt_thread_defunct_process,
     rt_thread_startup, 
     rt_thread_delete, 
     rt_thread_self, 
@@ -43,8 +50,12 @@ pub use self::thread::{
     rt_thread_suspend,
     rt_thread_control,
     rt_thread_sleep,
+    rt_thread_delay_until,
+    rt_thread_delay,
+    rt_thread_mdelay,
     rt_thread_set_priority,
-    rt_thread_aging
+    rt_thread_aging,
+    list_thread
 };
 pub use self::thread_priority_table::{
     ThreadPriorityTable,
@@ -54,4 +65,24 @@ pub use self::thread_priority_table::{
     get_highest_priority, 
     get_highest_priority_thread, 
     pop_thread};
-pub use self::kstack::{KernelStack};
\ No newline at end of file
+pub use self::kstack::{
+    KernelStack,
+    RT_STACK_FILL,
+    RT_STACK_GUARD_SIZE,
+    rt_system_stack_guard_init,
+    rt_stack_guard_switch,
+    rt_stack_check,
+    rt_stack_overflow_thread,
+};
+pub use self::signal::{
+    RtSigHandler,
+    RtSigAction,
+    RtSigInfo,
+    rt_thread_kill,
+    rt_signal_install,
+    rt_signal_mask,
+    rt_signal_unmask,
+    rt_signal_wait,
+    rt_thread_handle_sig,
+    rt_signal_check,
+};
\ No newline at end of file
@@ -70,6 +70,7 @@ impl Scheduler {
 
 /// 切换到指定线程
 fn switch_to_thread(thread: Arc<RtThread>) {
+    rt_stack_guard_switch(&thread);
     let stack_pointer = thread.inner.field_mut_ptr(|thread| &mut thread.stack_pointer);
     rt_hw_context_switch_to(stack_pointer);
 }
@@ -78,6 +79,7 @@ fn switch_to_thread(thread: Arc<RtThread>) {
 fn switch_to_thread_from_to(from_thread: Arc<RtThread>, to_thread: Arc<RtThread>) {
     let from_stack_pointer = from_thread.inner.field_mut_ptr(|thread| &mut thread.stack_pointer);
     let to_stack_pointer = to_thread.inner.field_mut_ptr(|thread| &mut thread.stack_pointer);
+    rt_stack_guard_switch(&to_thread);
     // hprintln!("switch_to_thread_from_to: from_stack_pointer: {:?}, to_stack_pointer: {:?}", from_stack_pointer, to_stack_pointer);
     rt_hw_context_switch(from_stack_pointer, to_stack_pointer);
 }
@@ -152,6 +154,14 @@ fn execute_thread_switch(context: ThreadSwitchContext) {
     // }
 
 
+    // 栈溢出检查：原线程的栈指针尚未保存，只检查保护区
+    if RT_USING_OVERFLOW_CHECK {
+        if let Some(from) = &from_thread {
+            rt_stack_check(from, false);
+        }
+        rt_stack_check(&to_thread, true);
+    }
+
     // 设置新线程状态为运行
     to_thread.inner.exclusive_access().stat = ThreadState::Running;
 
@@ -191,6 +201,10 @@ pub fn rt_schedule() {
             execute_thread_switch(context);
             // 开中断
             rt_hw_interrupt_enable(level);
+            // 线程切换回来后，处理在中断中发给本线程的信号
+            if RT_USING_SIGNALS {
+                rt_signal_check();
+            }
             return;
         } else {// 中断环境：execute_thread_switch() → 继续执行 → 开中断 → 返回
             // 在中断环境下切换
@@ -218,6 +232,7 @@ pub fn start_scheduler() -> Option<Arc<RtThread>>{
 
 /// 启动调度器(用户接口)
 pub fn rt_schedule_start(){
+    rt_system_stack_guard_init();
     let current_thread = start_scheduler();
     if current_thread.is_some() {
         switch_to_thread(current_thread.unwrap());
@@ -0,0 +1,394 @@
+//! 线程信号相关函数
+//!
+//! 参考 RT-Thread 的 signal.c 实现，信号处理函数在目标线程的上下文中执行：
+//! - 目标线程为当前线程（且处于线程模式）时，立即处理
+//! - 目标线程为就绪或挂起的其他线程时，在其栈上构造一个进入 _signal_entry 的栈帧，
+//!   线程下一次被调度时先执行信号处理函数，再返回到被打断的位置
+//! - 在中断中向当前线程发送信号时，标记 SignalPending，在下一次 rt_schedule 返回时处理
+//!
+//! 结构体：RtSigInfo、RtSigAction
+//! 函数：rt_thread_kill、rt_signal_install、rt_signal_mask、rt_signal_unmask、rt_signal_wait、rt_thread_handle_sig、rt_signal_check
+
+extern crate alloc;
+use alloc::vec;
+use alloc::sync::Arc;
+use spin::Mutex;
+use cortex_m::peripheral::scb::VectActive;
+use cortex_m::peripheral::SCB;
+
+use crate::rtthread_rt::rtdef::*;
+use crate::rtthread_rt::thread::*;
+use crate::rtthread_rt::hardware::*;
+use crate::rtthread_rt::timer::*;
+use crate::rtthread_rt::ipc::_ipc_remain_timeout;
+use crate::rtthread_rt::thread::thread::_thread_timeout_start;
+
+/// 信号处理函数
+pub type RtSigHandler = fn(i32);
+
+/// 信号的处理方式
+#[derive(Debug, Clone, Copy)]
+pub enum RtSigAction {
+    /// 默认处理（不做任何动作）
+    Default,
+    /// 忽略该信号
+    Ignore,
+    /// 用户处理函数
+    Handler(RtSigHandler),
+}
+
+/// 信号信息
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RtSigInfo {
+    /// 信号值
+    pub si_signo: i32,
+    /// 信号来源（SI_USER）
+    pub si_code: i32,
+    /// 附带的值
+    pub si_value: usize,
+}
+
+/// 信号对应的位
+fn sig_mask(signo: i32) -> u32 {
+    1u32 << signo
+}
+
+/// 信号值是否合法
+fn sig_valid(signo: i32) -> bool {
+    (0..RT_SIG_MAX).contains(&signo)
+}
+
+/// 是否处于线程模式（不在任何异常/中断处理中）
+fn in_thread_mode() -> bool {
+    matches!(SCB::vect_active(), VectActive::ThreadMode)
+}
+
+/// 判断线程是否为当前线程
+fn is_current_thread(thread: &Arc<RtThread>) -> bool {
+    rt_thread_self().is_some_and(|current| Arc::ptr_eq(&current, thread))
+}
+
+/// 停止线程上挂着的定时器（睡眠、IPC 超时或等待信号超时）
+fn stop_thread_timer(thread: &Arc<RtThread>) {
+    let timer = thread.inner.exclusive_access().timer.take();
+    if let Some(timer) = timer {
+        rt_timer_stop(&timer);
+    }
+}
+
+/// 信号处理入口
+/// 在目标线程的栈上运行，处理完所有信号后回到线程被打断时的上下文
+extern "C" fn _signal_entry(_parameter: usize) {
+    rt_thread_handle_sig(false);
+
+    let _level = rt_hw_interrupt_disable();
+    let stack_pointer = {
+        let thread = rt_thread_self().unwrap();
+        {
+            let mut inner = thread.inner.exclusive_access();
+            inner.stack_pointer = inner.sig_ret;
+            inner.sig_ret = 0;
+            inner.sig_stat &= !(ThreadState::Signal as u8);
+        }
+        // thread 在此作用域结束时释放，切换后本栈帧不会再返回
+        thread.inner.field_mut_ptr(|inner| &mut inner.stack_pointer)
+    };
+    // 不保存当前上下文，直接恢复线程被打断时的上下文（中断在切换时重新打开）
+    rt_hw_context_switch_to(stack_pointer);
+}
+
+/// 将信号递送给线程
+/// 1. 线程为当前线程：在线程模式下直接处理，否则标记在下一次调度时处理
+/// 2. 线程挂起：唤醒线程（错误码为 RT_EINTR），并构造信号处理栈帧
+/// 3. 线程就绪：构造信号处理栈帧，线程下一次运行时先处理信号
+fn _signal_deliver(thread: Arc<RtThread>) {
+    let level = rt_hw_interrupt_disable();
+
+    // 线程对挂起的信号不感兴趣
+    let (interested, sig_stat) = thread.inner.exclusive_session(|inner| {
+        (inner.sig_pending & inner.sig_mask != 0, inner.sig_stat)
+    });
+    if !interested {
+        rt_hw_interrupt_enable(level);
+        return;
+    }
+
+    if is_current_thread(&thread) {
+        if in_thread_mode() {
+            thread.inner.exclusive_access().sig_stat |= ThreadState::Signal as u8;
+            rt_hw_interrupt_enable(level);
+            rt_thread_handle_sig(true);
+        } else {
+            // 中断中无法在线程上下文执行处理函数，留到下一次调度时处理
+            thread.inner.exclusive_access().sig_stat |= ThreadState::Signal as u8 | ThreadState::SignalPending as u8;
+            rt_hw_interrupt_enable(level);
+        }
+        return;
+    }
+
+    let stat = thread.inner.exclusive_access().stat.get_stat();
+    if stat == (ThreadState::Suspend as u8) {
+        // 唤醒线程处理信号，停止其睡眠/等待定时器
+        stop_thread_timer(&thread);
+        if sig_stat & (ThreadState::SignalWait as u8) != 0 {
+            // 线程在 rt_signal_wait 中等待，由其自行取出信号
+            thread.inner.exclusive_access().error = RT_EOK;
+            rt_thread_resume(thread.clone());
+            rt_hw_interrupt_enable(level);
+            rt_schedule();
+            return;
+        }
+        thread.inner.exclusive_access().error = RT_EINTR;
+        rt_thread_resume(thread.clone());
+    } else if stat != (ThreadState::Ready as u8) {
+        // 线程尚未启动或已关闭，信号保持挂起
+        rt_hw_interrupt_enable(level);
+        return;
+    }
+
+    // 已经构造过信号处理栈帧，处理函数会一并处理新的信号
+    if sig_stat & (ThreadState::Signal as u8) != 0 {
+        rt_hw_interrupt_enable(level);
+        return;
+    }
+
+    // 在线程保存的上下文下方构造进入 _signal_entry 的栈帧
+    {
+        let mut inner = thread.inner.exclusive_access();
+        inner.sig_ret = inner.stack_pointer;
+        inner.stack_pointer = unsafe {
+            rt_hw_stack_init(
+                _signal_entry as usize,
+                0 as *mut u8,
+                (inner.sig_ret - 32) as usize,
+                0,
+            )
+        } as u32;
+        inner.sig_stat |= ThreadState::Signal as u8;
+    }
+    rt_hw_interrupt_enable(level);
+
+    rt_schedule();
+}
+
+/// 处理当前线程挂起且未被屏蔽的信号
+/// 在线程上下文中依次调用信号处理函数，处理后线程错误码被设置为 RT_EINTR
+/// @param clean_state 处理完成后是否清除信号状态
+pub fn rt_thread_handle_sig(clean_state: bool) {
+    let thread = match rt_thread_self() {
+        Some(thread) => thread,
+        None => return,
+    };
+
+    loop {
+        let level = rt_hw_interrupt_disable();
+        let next = thread.inner.exclusive_session(|inner| {
+            // 线程在等待信号时，由 rt_signal_wait 取出信号
+            if inner.sig_stat & (ThreadState::SignalWait as u8) != 0 {
+                return None;
+            }
+            let mask = inner.sig_mask;
+            let pos = inner.si_list.iter().position(|si| sig_mask(si.si_signo) & mask != 0)?;
+            let si = inner.si_list.remove(pos);
+            inner.sig_pending &= !sig_mask(si.si_signo);
+            let action = inner.sig_vectors.as_ref()
+                .map_or(RtSigAction::Default, |vectors| vectors[si.si_signo as usize]);
+            Some((si.si_signo, action))
+        });
+
+        let (signo, action) = match next {
+            Some(next) => next,
+            None => {
+                if clean_state {
+                    thread.inner.exclusive_access().sig_stat &=
+                        !(ThreadState::Signal as u8 | ThreadState::SignalPending as u8);
+                }
+                rt_hw_interrupt_enable(level);
+                return;
+            }
+        };
+        rt_hw_interrupt_enable(level);
+
+        if let RtSigAction::Handler(handler) = action {
+            handler(signo);
+        }
+
+        thread.inner.exclusive_access().error = RT_EINTR;
+    }
+}
+
+/// 检查当前线程是否有留到调度时处理的信号（由 rt_schedule 调用）
+pub fn rt_signal_check() {
+    if !in_thread_mode() || rt_hw_get_interrupt_level() != 0 {
+        return;
+    }
+    let thread = match rt_thread_self() {
+        Some(thread) => thread,
+        None => return,
+    };
+    let pending = thread.inner.exclusive_session(|inner| {
+        let pending = inner.sig_stat & (ThreadState::SignalPending as u8) != 0;
+        inner.sig_stat &= !(ThreadState::SignalPending as u8);
+        pending
+    });
+    if pending {
+        rt_thread_handle_sig(true);
+    }
+}
+
+/// 为当前线程安装信号处理方式
+/// 需配合 rt_signal_unmask 使用，信号才会生效
+/// @param signo 信号值（0 ~ RT_SIG_MAX-1）
+/// @param action 处理方式
+/// @return 旧的处理方式，信号值不合法时返回 None
+pub fn rt_signal_install(signo: i32, action: RtSigAction) -> Option<RtSigAction> {
+    if !sig_valid(signo) {
+        return None;
+    }
+    let thread = rt_thread_self()?;
+
+    let level = rt_hw_interrupt_disable();
+    let old = thread.inner.exclusive_session(|inner| {
+        let vectors = inner.sig_vectors
+            .get_or_insert_with(|| vec![RtSigAction::Default; RT_SIG_MAX as usize]);
+        core::mem::replace(&mut vectors[signo as usize], action)
+    });
+    rt_hw_interrupt_enable(level);
+    Some(old)
+}
+
+/// 屏蔽当前线程的指定信号
+/// 屏蔽后即使 rt_thread_kill 发送该信号，也不会被处理
+/// @param signo 信号值（0 ~ RT_SIG_MAX-1）
+pub fn rt_signal_mask(signo: i32) {
+    if !sig_valid(signo) {
+        return;
+    }
+    if let Some(thread) = rt_thread_self() {
+        let level = rt_hw_interrupt_disable();
+        thread.inner.exclusive_access().sig_mask &= !sig_mask(signo);
+        rt_hw_interrupt_enable(level);
+    }
+}
+
+/// 解除当前线程对指定信号的屏蔽
+/// 若该信号已经挂起，则立即处理
+/// @param signo 信号值（0 ~ RT_SIG_MAX-1）
+pub fn rt_signal_unmask(signo: i32) {
+    if !sig_valid(signo) {
+        return;
+    }
+    if let Some(thread) = rt_thread_self() {
+        let level = rt_hw_interrupt_disable();
+        let deliver = thread.inner.exclusive_session(|inner| {
+            inner.sig_mask |= sig_mask(signo);
+            inner.sig_mask & inner.sig_pending != 0
+        });
+        rt_hw_interrupt_enable(level);
+        if deliver {
+            _signal_deliver(thread);
+        }
+    }
+}
+
+/// 等待信号集合中的任一信号到达
+/// 信号需先通过 rt_signal_unmask 解除屏蔽，才能唤醒等待的线程
+/// @param set 等待的信号集合（按位表示）
+/// @param si 收到的信号信息
+/// @param timeout 等待时间（tick）：RT_WAITING_NO 不等待，小于0表示永久等待
+/// @return RT_EOK: 收到信号
+///         RT_ETIMEOUT: 超时
+///         RT_EINVAL: 参数错误
+pub fn rt_signal_wait(set: u32, si: &mut RtSigInfo, timeout: i32) -> RtErrT {
+    if set == 0 {
+        return RT_EINVAL;
+    }
+    let thread = match rt_thread_self() {
+        Some(thread) => thread,
+        None => return RT_ERROR,
+    };
+    *si = RtSigInfo::default();
+    let start_tick = rt_tick_get();
+
+    loop {
+        let level = rt_hw_interrupt_disable();
+
+        // 取出第一个匹配的信号
+        let received = thread.inner.exclusive_session(|inner| {
+            match inner.si_list.iter().position(|node| sig_mask(node.si_signo) & set != 0) {
+                Some(pos) => {
+                    *si = inner.si_list.remove(pos);
+                    inner.sig_pending &= !sig_mask(si.si_signo);
+                    true
+                }
+                None => false,
+            }
+        });
+        if received {
+            rt_hw_interrupt_enable(level);
+            return RT_EOK;
+        }
+
+        // 没有匹配的信号，在剩余时间内挂起等待
+        let remain = _ipc_remain_timeout(timeout, start_tick);
+        if remain == RT_WAITING_NO {
+            rt_hw_interrupt_enable(level);
+            return RT_ETIMEOUT;
+        }
+        thread.inner.exclusive_access().error = RT_EOK;
+        rt_thread_suspend(thread.clone());
+        thread.inner.exclusive_access().sig_stat |= ThreadState::SignalWait as u8;
+        if remain > 0 {
+            _thread_timeout_start(&thread, remain as u32);
+        }
+        rt_hw_interrupt_enable(level);
+        rt_schedule();
+
+        // 被提前唤醒时超时定时器仍在计时，停止后才能在下一轮重新计时
+        let level = rt_hw_interrupt_disable();
+        let (error, timer) = thread.inner.exclusive_session(|inner| {
+            inner.sig_stat &= !(ThreadState::SignalWait as u8);
+            (inner.error, inner.timer.take())
+        });
+        if let Some(timer) = timer {
+            rt_timer_stop_sync(&timer);
+        }
+        if error == RT_ETIMEOUT {
+            thread.inner.exclusive_access().error = RT_EOK;
+            rt_hw_interrupt_enable(level);
+            return RT_ETIMEOUT;
+        }
+        // 被不在 set 中的信号唤醒（其处理函数已执行），重新检查并继续等待剩余时间
+        rt_hw_interrupt_enable(level);
+    }
+}
+
+/// 向线程发送信号
+/// @param thread 接收信号的线程
+/// @param sig 信号值（0 ~ RT_SIG_MAX-1）
+/// @return RT_EOK: 发送成功
+///         RT_EINVAL: 信号值不合法
+pub fn rt_thread_kill(thread: Arc<RtThread>, sig: i32) -> RtErrT {
+    if !sig_valid(sig) {
+        return RT_EINVAL;
+    }
+    let si = RtSigInfo {
+        si_signo: sig,
+        si_code: SI_USER,
+        si_value: 0,
+    };
+
+    let level = rt_hw_interrupt_disable();
+    thread.inner.exclusive_session(|inner| {
+        // 同一信号已挂起时只更新信号信息
+        match inner.si_list.iter_mut().find(|node| node.si_signo == sig) {
+            Some(node) => *node = si,
+            None => inner.si_list.push(si),
+        }
+        inner.sig_pending |= sig_mask(sig);
+    });
+    rt_hw_interrupt_enable(level);
+
+    _signal_deliver(thread);
+    RT_EOK
+}
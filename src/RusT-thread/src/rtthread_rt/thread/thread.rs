@@ -1,7 +1,7 @@
 //! 线程相关函数
 //! 
 //! 结构体：RtThread、RtThreadInner
-//! 函数：rt_thread_create、rt_thread_self、rt_thread_delete、rt_thread_startup、rt_thread_suspend、rt_thread_sleep、rt_thread_control、rt_thread_resume、rt_thread_yield
+//! 函数：rt_thread_create、rt_thread_init、rt_thread_spawn、rt_thread_exit、rt_thread_defunct_process、rt_thread_self、rt_thread_delete、rt_thread_startup、rt_thread_suspend、rt_thread_sleep、rt_thread_control、rt_thread_resume、rt_thread_yield、list_thread
 
 use lazy_static::lazy_static;
 
@@ -16,10 +16,13 @@ use crate::rtthread_rt::thread::*;
 use crate::rtthread_rt::kservice::RTIntrFreeCell;
 use crate::rtthread_rt::hardware::*;
 use crate::rtthread_rt::timer::*;
+use crate::rtthread_rt::ipc::{IPCBase, RtMutex, _mutex_inherited_priority, _mutex_thread_defunct};
 use crate::rtthread_rt::rtconfig::*;
+use crate::rtthread_rt::mem::allocator::with_static_arena;
 
 use core::fmt::Debug;
-use alloc::sync::Arc;
+use core::mem::{align_of, size_of};
+use alloc::sync::{Arc, Weak};
 use alloc::alloc::{
     alloc,
     dealloc,
@@ -34,6 +37,9 @@ lazy_static! {
     /// 总的线程列表，用户可从中获取所有线程
     static ref RT_THREAD_LIST: RTIntrFreeCell<Vec<Arc<RtThread>>> = unsafe { RTIntrFreeCell::new(Vec::new()) };
 
+    /// 已退出、等待回收栈的线程
+    static ref RT_THREAD_DEFUNCT: RTIntrFreeCell<Vec<Arc<RtThread>>> = unsafe { RTIntrFreeCell::new(Vec::new()) };
+
 }
 
 pub struct RtThreadInner {
@@ -66,6 +72,12 @@ pub struct RtThreadInner {
     /// 线程相关信息
     pub entry: usize, // 函数入口
 
+    /// 入口函数参数
+    pub parameter: usize,
+
+    /// 由 rt_thread_spawn 创建的线程要执行的闭包，线程开始运行时取出
+    pub closure: Option<Box<dyn FnOnce() + Send>>,
+
     /// tick
     /// 线程的初始时间片
     /// 静态，初始化时设置，之后不再改变
@@ -77,10 +89,30 @@ pub struct RtThreadInner {
     pub remaining_tick: usize,
 
     /// timer
-    /// 线程的睡眠定时器
-    /// 当线程进入睡眠状态时，会创建一个单次定时器，当定时器到期时，会唤醒线程
+    /// 线程当前正在计时的超时定时器（睡眠、IPC 等待或等待信号），未计时时为 None
+    /// 定时器到期或线程被提前唤醒时取走
     pub timer: Option<TimerHandle>,
 
+    /// 线程的超时定时器
+    /// 随线程创建，睡眠、IPC 等待与等待信号重复使用，不再分配
+    pub timeout_timer: Option<TimerHandle>,
+
+    /// 线程的延时定时器
+    /// 随线程创建，rt_thread_delay 等延时重复使用，不再分配
+    pub delay_timer: Option<TimerHandle>,
+
+    /// 线程当前挂起等待的 IPC 对象
+    /// 线程被删除时需要将其从该 IPC 的等待队列中移除
+    pub suspend_ipc: Option<Arc<IPCBase>>,
+
+    /// 线程当前持有的 mutex
+    /// 线程优先级被重置时，据此重新计算继承自等待者的优先级
+    pub taken_mutexes: Vec<Arc<RtMutex>>,
+
+    /// 线程当前等待的 mutex
+    /// 线程在等待中被删除时，据此重新计算持有者继承的优先级
+    pub wait_mutex: Option<Arc<RtMutex>>,
+
     /// context
     /// 线程的栈
     pub kernel_stack: KernelStack,
@@ -88,6 +120,33 @@ pub struct RtThreadInner {
     /// 线程的栈指针
     pub stack_pointer: u32,
 
+    /// event
+    /// 线程等待的事件集合（被唤醒后为实际收到的事件）
+    pub event_set: u32,
+
+    /// 线程等待事件的选项（RT_EVENT_FLAG_AND / RT_EVENT_FLAG_OR / RT_EVENT_FLAG_CLEAR）
+    pub event_info: u8,
+
+    /// signal
+    /// 信号状态位（ThreadState::Signal / SignalWait / SignalPending），与 stat 分开保存，
+    /// 避免调度器改写 stat 时丢失
+    pub sig_stat: u8,
+
+    /// 已发送但尚未处理的信号
+    pub sig_pending: u32,
+
+    /// 信号屏蔽字，置位表示允许处理该信号
+    pub sig_mask: u32,
+
+    /// 进入信号处理前线程的栈指针
+    pub sig_ret: u32,
+
+    /// 信号处理方式表，第一次安装信号时分配
+    pub sig_vectors: Option<Vec<RtSigAction>>,
+
+    /// 挂起的信号信息
+    pub si_list: Vec<RtSigInfo>,
+
 
 }
 
@@ -139,6 +198,48 @@ impl RtThread {
         core::str::from_utf8(name_slice)
             .unwrap_or("invalid utf8")
     }
+
+    /// 线程栈的大小（字节）
+    pub fn stack_size(&self) -> usize {
+        self.inner.exclusive_access().kernel_stack.size()
+    }
+
+    /// 线程栈的最大使用量（字节），用于确定合适的栈大小
+    pub fn stack_max_used(&self) -> usize {
+        self.inner.exclusive_access().kernel_stack.max_used()
+    }
+}
+
+/// Arc 分配的大小（引用计数与数据），额外预留对齐所需的空间
+const fn arc_alloc_size<T>() -> usize {
+    2 * size_of::<usize>() + size_of::<T>() + align_of::<T>()
+}
+
+/// 静态线程对象所需的空间：线程对象、超时与延时定时器及其回调（回调捕获线程的弱引用与到期处理函数）
+pub const RT_THREAD_STORAGE_SIZE: usize = arc_alloc_size::<RtThread>()
+    + 2 * arc_alloc_size::<Mutex<RtTimer>>()
+    + 2 * (size_of::<(Weak<RtThread>, fn(Arc<RtThread>))>() + align_of::<usize>());
+
+/// 静态线程对象的存储空间，由 rt_thread_init 使用
+///
+/// 使用示例：
+/// ```rust
+/// static mut STORAGE: RtThreadStorage = RtThreadStorage::new();
+/// let storage = unsafe { &mut *(&raw mut STORAGE) };
+/// ```
+#[repr(C, align(8))]
+pub struct RtThreadStorage([u8; RT_THREAD_STORAGE_SIZE]);
+
+impl RtThreadStorage {
+    pub const fn new() -> Self {
+        RtThreadStorage([0; RT_THREAD_STORAGE_SIZE])
+    }
+
+    /// 存储空间的地址范围，可用于确认线程对象位于其中
+    pub fn range(&self) -> core::ops::Range<usize> {
+        let start = self.0.as_ptr() as usize;
+        start..start + RT_THREAD_STORAGE_SIZE
+    }
 }
 
 /// 上下文，用于线程切换
@@ -160,40 +261,17 @@ impl RtContext {
 }
 
 
-/// 创建线程
-/// @param name 线程名称
-/// @param entry 线程入口函数
-/// @param stack_size 线程栈大小
-/// @param priority 线程优先级
-/// @param tick 线程时间片
-/// @return 线程对象
-pub fn rt_thread_create(name: &str, entry: usize, stack_size: usize, priority: u8, tick: usize) -> Arc<RtThread> {
-    // todo 健壮性检查：同名线程是否存在、栈大小是否合理、优先级是否合理、时间片是否合理
-
-    // 检查线程是否存在
-    // if RT_THREAD_LIST.exclusive_access().iter().any(|thread| thread.name == name) {
-    //     hprintln!("Warning: thread {} already exists", name);
-    // }
-    // if stack_size > RT_THREAD_STACK_SIZE_MAX {
-    //     hprintln!("Warning: stack_size {} is too large", stack_size);
-    // }
-    if priority > RT_THREAD_PRIORITY_MAX {
-        hprintln!("Warning: priority {} is too large", priority);
+/// 检查线程的优先级与时间片
+/// @return Err(RtError::InvalidArgument): 优先级超出范围或时间片为0
+fn _thread_check(priority: u8, tick: usize) -> Result<(), RtError> {
+    if priority >= RT_THREAD_PRIORITY_MAX || tick == 0 {
+        return Err(RtError::InvalidArgument);
     }
-    // if tick > RT_THREAD_TICK_MAX {
-    //     hprintln!("Warning: tick {} is too large", tick);
-    // }
+    Ok(())
+}
 
-    let mut kernel_stack = KernelStack::new(stack_size);
-    let stack_pointer = unsafe {
-        rt_hw_stack_init(
-            entry,
-            0 as *mut u8,
-            kernel_stack.top() as usize,
-            0 as usize
-        )
-    };
-    // hprintln!("stack_pointer in rt_thread_create: {:x}", stack_pointer.clone());
+/// 将线程名转换为定长的名称数组，超出 RT_NAME_MAX 的部分被截断
+fn _thread_name_array(name: &str) -> [u8; RT_NAME_MAX] {
     let name_bytes = name.as_bytes();
     let len = name_bytes.len().min(RT_NAME_MAX);
     
@@ -204,10 +282,33 @@ pub fn rt_thread_create(name: &str, entry: usize, stack_size: usize, priority: u
     if len < RT_NAME_MAX {
         name_array[len] = 0;
     }
-    
-    let timer_callback = move || {
-        hprintln!("timer_callback");
+    name_array
+}
+
+/// 线程列表中是否已有同名且未关闭的线程（已关闭、等待回收的线程不参与同名检查）
+fn _thread_name_used(list: &[Arc<RtThread>], name: &[u8; RT_NAME_MAX]) -> bool {
+    list.iter().any(|t| {
+        t.name == *name
+            && t.inner.exclusive_access().stat.get_stat() != (ThreadState::Close as u8)
+    })
+}
+
+/// 初始化线程对象（rt_thread_create 与 rt_thread_init 的公共部分）
+/// 初始化栈帧，创建线程对象及其定时器并加入线程列表
+/// @param storage 静态线程对象的存储空间，为 None 时线程对象从堆上分配
+/// @return Err(RtError::Busy): 已存在同名且未关闭的线程
+fn _thread_init(storage: Option<&'static mut RtThreadStorage>, name: &str, entry: usize, parameter: usize, kernel_stack: KernelStack, priority: u8, tick: usize) -> Result<Arc<RtThread>, RtError> {
+    let stack_pointer = unsafe {
+        rt_hw_stack_init(
+            entry,
+            parameter as *mut u8,
+            kernel_stack.top() as usize,
+            rt_thread_exit as usize
+        )
     };
+    // hprintln!("stack_pointer in rt_thread_create: {:x}", stack_pointer.clone());
+    let name_array = _thread_name_array(name);
+    
 // hprintln!("timer in rt_thread_create");
     let inner =unsafe {
         RTIntrFreeCell::new(RtThreadInner {
@@ -218,28 +319,210 @@ pub fn rt_thread_create(name: &str, entry: usize, stack_size: usize, priority: u
         number_mask: 0,
         high_mask: 0,
         entry,
+        parameter,
+        closure: None,
         init_tick: tick,
         remaining_tick: tick,
         kernel_stack,
         stack_pointer: stack_pointer as u32,
         timer: None,
+        timeout_timer: None,
+        delay_timer: None,
+        suspend_ipc: None,
+        taken_mutexes: Vec::new(),
+        wait_mutex: None,
+        event_set: 0,
+        event_info: 0,
+        sig_stat: 0,
+        sig_pending: 0,
+        sig_mask: 0,
+        sig_ret: 0,
+        sig_vectors: None,
+        si_list: Vec::new(),
         })
     };
-    let thread = RtThread {
-        name: name_array,
-        object_type: 0,
-        inner,
-        cleanup: None,
+    // 定时器回调只持有线程的弱引用，避免线程与定时器互相引用
+    let create = move || Arc::new_cyclic(|weak: &Weak<RtThread>| {
+        inner.exclusive_session(|inner| {
+            inner.timeout_timer = Some(thread_timer(name, weak.clone(), thread_timeout));
+            inner.delay_timer = Some(thread_timer(name, weak.clone(), thread_delay_timeout));
+        });
+        RtThread {
+            name: name_array,
+            object_type: 0,
+            inner,
+            cleanup: None,
+        }
+    });
+    let thread_arc = match storage {
+        Some(storage) => with_static_arena(&mut storage.0, create),
+        None => create(),
     };
-    let thread_arc = Arc::new(thread);
-    RT_THREAD_LIST.exclusive_access().push(thread_arc.clone()); 
+    // 同名检查与加入线程列表在同一临界区内完成；已关闭（等待回收）的线程不参与同名检查
+    RT_THREAD_LIST.exclusive_session(|list| {
+        if _thread_name_used(list, &thread_arc.name) {
+            return Err(RtError::Busy);
+        }
+        list.push(thread_arc.clone());
+        Ok(())
+    })?;
     // hprintln!("rt_thread_create finished.");
-    thread_arc
+    Ok(thread_arc)
+}
+
+/// 创建线程
+/// @param name 线程名称
+/// @param entry 线程入口函数
+/// @param parameter 入口函数参数
+/// @param stack_size 线程栈大小，需为 2 的幂且不小于 RT_THREAD_STACK_SIZE_MIN
+/// @param priority 线程优先级
+/// @param tick 线程时间片
+/// @return Ok: 线程对象
+///         Err(RtError::InvalidArgument): 栈大小、优先级或时间片不合法
+///         Err(RtError::NoMemory): 栈内存分配失败
+///         Err(RtError::Busy): 已存在同名线程
+pub fn rt_thread_create(name: &str, entry: usize, parameter: usize, stack_size: usize, priority: u8, tick: usize) -> Result<Arc<RtThread>, RtError> {
+    _thread_check(priority, tick)?;
+    if stack_size < RT_THREAD_STACK_SIZE_MIN {
+        return Err(RtError::InvalidArgument);
+    }
+    let kernel_stack = KernelStack::try_new(stack_size)?;
+    _thread_init(None, name, entry, parameter, kernel_stack, priority, tick)
+}
+
+/// 初始化静态线程
+/// 线程对象及其超时、延时定时器位于 storage 中，线程栈使用调用者提供的静态缓冲区，都不从堆上分配，
+/// 线程被删除后也不会释放；线程的睡眠、延时与 IPC 超时重复使用这些定时器。
+/// storage 与 stack 只能用于一个线程，线程被删除后也不能再用于新的线程
+/// @param storage 线程对象的静态存储空间
+/// @param name 线程名称
+/// @param entry 线程入口函数
+/// @param parameter 入口函数参数
+/// @param stack 静态栈缓冲区，对齐后不小于 RT_THREAD_STACK_SIZE_MIN
+/// @param priority 线程优先级
+/// @param tick 线程时间片
+/// @return Ok: 线程对象
+///         Err(RtError::InvalidArgument): 栈缓冲区太小，或优先级、时间片不合法
+///         Err(RtError::Busy): 已存在同名线程
+pub fn rt_thread_init(storage: &'static mut RtThreadStorage, name: &str, entry: usize, parameter: usize, stack: &'static mut [u8], priority: u8, tick: usize) -> Result<Arc<RtThread>, RtError> {
+    // 在接管静态缓冲区之前完成全部检查，失败时缓冲区不会被改写
+    _thread_check(priority, tick)?;
+    if KernelStack::static_size(stack) < RT_THREAD_STACK_SIZE_MIN {
+        return Err(RtError::InvalidArgument);
+    }
+    let name_array = _thread_name_array(name);
+    if RT_THREAD_LIST.exclusive_session(|list| _thread_name_used(list, &name_array)) {
+        return Err(RtError::Busy);
+    }
+    let kernel_stack = KernelStack::from_static(stack);
+    _thread_init(Some(storage), name, entry, parameter, kernel_stack, priority, tick)
+}
+
+/// 闭包线程的入口函数：取出当前线程保存的闭包并执行
+extern "C" fn rt_thread_spawn_entry(_arg: usize) {
+    let closure = rt_thread_self().and_then(|thread| thread.inner.exclusive_access().closure.take());
+    if let Some(closure) = closure {
+        closure();
+    }
+}
+
+/// 以闭包创建并启动线程
+/// 闭包保存在线程对象中，线程开始运行时执行；闭包返回后线程经 rt_thread_exit 退出
+/// @param name 线程名称
+/// @param stack_size 线程栈大小
+/// @param priority 线程优先级
+/// @param tick 线程时间片
+/// @param f 线程要执行的闭包
+/// @return Ok: 线程对象
+///         Err: 创建失败，同 rt_thread_create
+pub fn rt_thread_spawn<F>(name: &str, stack_size: usize, priority: u8, tick: usize, f: F) -> Result<Arc<RtThread>, RtError>
+where
+    F: FnOnce() + Send + 'static,
+{
+    let thread = rt_thread_create(name, rt_thread_spawn_entry as usize, 0, stack_size, priority, tick)?;
+    thread.inner.exclusive_access().closure = Some(Box::new(f));
+    rt_thread_startup(thread.clone());
+    Ok(thread)
+}
+
+/// 将线程标记为关闭并放入待回收队列（需在关中断时调用）
+/// 线程被移出就绪队列，定时器被停止，栈等资源由空闲线程回收
+fn thread_defunct(thread: &Arc<RtThread>) {
+    // 已删除的线程不再重复入队（如先调用 rt_thread_delete 删除自身再返回）
+    if thread.inner.exclusive_access().stat.get_stat() == (ThreadState::Close as u8) {
+        return;
+    }
+    let _ = remove_thread(thread.clone());
+    let (timeout_timer, delay_timer) = thread.inner.exclusive_session(|inner| {
+        inner.timer = None;
+        (inner.timeout_timer.clone(), inner.delay_timer.clone())
+    });
+    for timer in [timeout_timer, delay_timer].into_iter().flatten() {
+        rt_timer_stop_sync(&timer);
+    }
+    // 从等待队列中移除，避免 IPC 唤醒已删除的线程（如将 mutex 转交给它）
+    let ipc = thread.inner.exclusive_access().suspend_ipc.take();
+    if let Some(ipc) = ipc {
+        ipc.thread_queue.exclusive_session(|queue| queue.retain(|t| !Arc::ptr_eq(t, thread)));
+    }
+    // 释放持有的 mutex，避免等待者永久阻塞；同时解除线程与 mutex 之间的循环引用
+    _mutex_thread_defunct(thread);
+    thread.inner.exclusive_access().stat = ThreadState::Close;
+    RT_THREAD_DEFUNCT.exclusive_access().push(thread.clone());
+}
+
+/// 线程退出
+/// 作为每个线程的返回地址（LR）安装，线程入口函数返回时自动调用：
+/// 将线程标记为关闭并移出就绪队列，放入待回收队列后切换到其他线程，
+/// 清理函数与栈的回收由空闲线程完成
+pub extern "C" fn rt_thread_exit() {
+    let thread = match rt_thread_self() {
+        Some(thread) => thread,
+        None => {
+            // 没有可返回的地址，返回会跳转到无效的 LR
+            hprintln!("rt_thread_exit: no current thread!");
+            loop {}
+        }
+    };
+
+    let level = rt_hw_interrupt_disable();
+    thread_defunct(&thread);
+    drop(thread);
+    rt_hw_interrupt_enable(level);
+    rt_schedule();
+
+    // 不会运行到这里
+    hprintln!("rt_thread_exit: should not reach here!");
+    loop {}
 }
 
+/// 回收待回收队列中的线程（由空闲线程调用）
+/// 执行清理函数，释放栈与定时器，并将线程移出线程列表
+pub fn rt_thread_defunct_process() {
+    loop {
+        let thread = match RT_THREAD_DEFUNCT.exclusive_access().pop() {
+            Some(thread) => thread,
+            None => return,
+        };
 
+        if let Some(cleanup) = thread.cleanup {
+            cleanup(Arc::as_ptr(&thread) as *mut RtThread);
+        }
+
+        RT_THREAD_LIST.exclusive_access().retain(|t| !Arc::ptr_eq(t, &thread));
+
+        // 用户可能仍持有线程句柄，因此显式释放栈与定时器，而不依赖 Arc 的释放
+        let (stack, timers) = thread.inner.exclusive_session(|inner| {
+            // 未运行就被删除的闭包线程，闭包在此释放
+            inner.closure = None;
+            (core::mem::replace(&mut inner.kernel_stack, KernelStack::new_empty()),
+                [inner.timer.take(), inner.timeout_timer.take(), inner.delay_timer.take()])
+        });
+        drop(timers);
+        drop(stack);
+    }
+}
 
-//todo 是否需要完成 初始化静态线程
 
 /// 获取当前线程
 /// @return 当前线程对象
@@ -249,6 +532,7 @@ pub fn rt_thread_self() -> Option<Arc<RtThread>> {
 
 
 /// 删除线程
+/// 线程被标记为关闭并放入待回收队列，其栈、定时器等资源由空闲线程回收
 /// @param thread 线程对象
 /// @return RT_EOK: 删除成功
 ///         : 删除失败
@@ -256,13 +540,10 @@ pub fn rt_thread_delete(thread: Arc<RtThread>) -> RtErrT {
     if thread.inner.exclusive_access().stat.get_stat() == (ThreadState::Close as u8) {
         return RT_EOK;
     }
-    if thread.inner.exclusive_access().stat.get_stat() != (ThreadState::Init as u8) {
-        let _ = remove_thread(thread.clone());
-    }
     
     let level = rt_hw_interrupt_disable();
 
-    thread.inner.exclusive_access().stat = ThreadState::Close; 
+    thread_defunct(&thread);
     // 注意：不要在删除时调用调度器，避免在MFQ策略下触发batch_aging导致数据损坏
     // 调度器会在适当的时候自动调用
     if get_current_scheduling_policy_name() != "Multi-Level Feedback Queue" {
@@ -324,8 +605,85 @@ pub fn rt_thread_suspend(thread: Arc<RtThread>) -> RtErrT {
     RT_EOK
 }
 
+/// 创建线程的单次定时器，到期时以线程为参数调用 timeout
+/// 回调只持有线程的弱引用，避免线程与定时器互相引用
+fn thread_timer(name: &str, thread: Weak<RtThread>, timeout: fn(Arc<RtThread>)) -> TimerHandle {
+    let timer_callback = move || {
+        if let Some(thread) = thread.upgrade() {
+            timeout(thread);
+        }
+    };
+    Arc::new(Mutex::new(RtTimer::new(
+        name,
+        0,
+        0x0,  // 单次硬定时器
+        Some(Box::new(timer_callback)),
+        0,
+        0,
+    )))
+}
+
+/// 超时定时器到期：根据线程等待的对象唤醒线程
+/// 1. 等待 IPC：从等待队列中移除，错误码为 RT_ETIMEOUT；已不在队列中说明线程已被唤醒
+/// 2. 等待信号：错误码为 RT_ETIMEOUT
+/// 3. 睡眠：错误码为 RT_EOK
+fn thread_timeout(thread: Arc<RtThread>) {
+    // 线程可能已被提前唤醒，此时定时器已被取走
+    let (timer, ipc, sig_stat) = thread.inner.exclusive_session(|inner| {
+        (inner.timer.take(), inner.suspend_ipc.clone(), inner.sig_stat)
+    });
+    if timer.is_none() {
+        return;
+    }
+    let error = match ipc {
+        Some(ipc) => {
+            let removed = ipc.thread_queue.exclusive_session(|queue| {
+                match queue.iter().position(|t| Arc::ptr_eq(t, &thread)) {
+                    Some(pos) => {
+                        queue.remove(pos);
+                        true
+                    }
+                    None => false,
+                }
+            });
+            if !removed {
+                return;
+            }
+            RT_ETIMEOUT
+        }
+        None if sig_stat & (ThreadState::SignalWait as u8) != 0 => RT_ETIMEOUT,
+        None => RT_EOK,
+    };
+    thread.inner.exclusive_access().error = error;
+    rt_thread_resume(thread);
+}
+
+/// 以线程的超时定时器开始计时（睡眠、IPC 等待与等待信号共用），到期时由 thread_timeout 唤醒线程
+/// 需在关中断时调用；线程被提前唤醒时取走 timer 并停止定时器
+/// @param thread 线程对象
+/// @param tick 超时时间（tick）
+/// @return RT_EOK: 成功
+///         RT_ERROR: 线程已有正在计时的超时定时器，或线程已被回收
+pub fn _thread_timeout_start(thread: &Arc<RtThread>, tick: u32) -> RtErrT {
+    let timer = thread.inner.exclusive_session(|inner| {
+        if inner.timer.is_some() {
+            return None;
+        }
+        inner.timer = inner.timeout_timer.clone();
+        inner.timer.clone()
+    });
+    let timer = match timer {
+        Some(timer) => timer,
+        None => return RT_ERROR,
+    };
+    rt_timer_control(&timer, TimerControlCmd::SetTime(tick));
+    rt_timer_start(timer);
+    RT_EOK
+}
+
 /// 使线程进入睡眠状态
 /// 让权给其他线程
+/// 使用线程自身的超时定时器，不会在每次调用时分配内存；当前线程延时请使用 rt_thread_delay / rt_thread_mdelay
 /// * `thread` 线程对象
 /// * `tick` 睡眠时间
 /// @return RT_EOK: 睡眠成功
@@ -340,54 +698,151 @@ pub fn rt_thread_sleep(thread: Arc<RtThread>, tick: usize) -> RtErrT {
     }
     // hprintln!("rt_thread_sleep after check: level: {}", rt_hw_get_interrupt_level());
 
+    let level = rt_hw_interrupt_disable();
     // 设置错误状态为超时，表示线程正在等待
     thread.inner.exclusive_access().error = RT_ETIMEOUT;
 
     // hprintln!("rt_thread_sleep after set error: level: {}", rt_hw_get_interrupt_level());
-
-    // 创建睡眠定时器回调
-    let thread_clone = thread.clone();
-    let timer_callback = move || {
-        hprintln!("timer_callback: resume thread");
-        let timer = thread_clone.inner.exclusive_access().timer.take().unwrap();
-        hprintln!("timer_callback: stop timer");
-        rt_timer_stop(&timer);
-        hprintln!("timer_callback: stop timer done");
-        // 清空定时器
-        thread_clone.inner.exclusive_access().timer = None;
-        // 清空错误状态
-        thread_clone.inner.exclusive_access().error = 0;
-        // 在定时器回调中恢复线程
-        rt_thread_resume(thread_clone.clone());
-    };
-
-    // hprintln!("rt_thread_sleep after timer callback: level: {}", rt_hw_get_interrupt_level());
-    if thread.inner.exclusive_access().timer.is_some() {
+    // 启动超时定时器，到期时清空错误状态并恢复线程
+    if _thread_timeout_start(&thread, tick as u32) != RT_EOK {
+        rt_hw_interrupt_enable(level);
         hprintln!("Warning: rt_thread_sleep: timer already exists");
         return RT_ERROR;
     }
-    // 创建单次定时器
-    let timer = Arc::new(Mutex::new(RtTimer::new(
-        thread.thread_name(),
-        0,
-        0x0,  // 单次定时器0，不是周期定时器2
-        Some(Box::new(timer_callback)),
-        tick as u32,
-        tick as u32,
-    )));
-    // 将定时器句柄保存到线程中，以便需要时可以停止
-    
-    thread.inner.exclusive_access().timer = Some(timer.clone());   
-    // hprintln!("rt_thread_sleep after set timer: level: {}", rt_hw_get_interrupt_level());
-    // 启动定时器
-    timer::rt_timer_start(timer.clone());
     // hprintln!("rt_thread_sleep after timer start: level: {}", rt_hw_get_interrupt_level());
     // 挂起线程
     rt_thread_suspend(thread.clone());
+    rt_hw_interrupt_enable(level);
     // hprintln!("rt_thread_sleep after suspend: level: {}", rt_hw_get_interrupt_level());
     RT_EOK
 }
 
+/// 延时定时器到期：延时正常结束；线程若已被提前唤醒（不再挂起），保留 RT_EINTR
+fn thread_delay_timeout(thread: Arc<RtThread>) {
+    let level = rt_hw_interrupt_disable();
+    let suspended = thread.inner.exclusive_session(|inner| {
+        let suspended = inner.stat.get_stat() == (ThreadState::Suspend as u8);
+        if suspended {
+            inner.error = RT_EOK;
+        }
+        suspended
+    });
+    rt_hw_interrupt_enable(level);
+    if suspended {
+        rt_thread_resume(thread);
+    }
+}
+
+/// 启动延时定时器并挂起线程（需在关中断时调用，开中断后才切换线程）
+/// 错误码预置为 RT_EINTR，定时器到期时改为 RT_EOK
+/// @return RT_EOK: 成功
+///         RT_ERROR: 线程不处于就绪或运行状态
+fn thread_delay_start(thread: &Arc<RtThread>, tick: u32) -> RtErrT {
+    let timer = match thread.inner.exclusive_access().delay_timer.clone() {
+        Some(timer) => timer,
+        None => return RT_ERROR,
+    };
+    thread.inner.exclusive_access().error = RT_EINTR;
+    rt_timer_control(&timer, TimerControlCmd::SetTime(tick));
+    rt_timer_start(timer.clone());
+    if rt_thread_suspend(thread.clone()) != RT_EOK {
+        rt_timer_stop_sync(&timer);
+        return RT_ERROR;
+    }
+    RT_EOK
+}
+
+/// 延时结束（线程被唤醒）后调用，提前唤醒时停止延时定时器
+/// @return RT_EOK: 延时结束
+///         RT_EINTR: 被 rt_thread_resume 或信号提前唤醒
+fn thread_delay_finish(thread: &Arc<RtThread>) -> RtErrT {
+    let error = thread.inner.exclusive_access().error;
+    if error != RT_EOK {
+        let timer = thread.inner.exclusive_access().delay_timer.clone();
+        if let Some(timer) = timer {
+            rt_timer_stop_sync(&timer);
+        }
+    }
+    error
+}
+
+/// 当前线程延时
+/// 使用线程自身的延时定时器，不会在每次调用时分配内存
+/// @param tick 延时时间（tick），为0时仅让出CPU
+/// @return RT_EOK: 延时结束
+///         RT_EINTR: 被 rt_thread_resume 或信号提前唤醒
+///         RT_ERROR: 不在线程中调用
+pub fn rt_thread_delay(tick: u32) -> RtErrT {
+    let thread = match rt_thread_self() {
+        Some(thread) => thread,
+        None => return RT_ERROR,
+    };
+    if tick == 0 {
+        return rt_thread_yield();
+    }
+
+    let level = rt_hw_interrupt_disable();
+    let result = thread_delay_start(&thread, tick);
+    rt_hw_interrupt_enable(level);
+    if result != RT_EOK {
+        return result;
+    }
+    rt_schedule();
+    thread_delay_finish(&thread)
+}
+
+/// 当前线程延时（毫秒）
+/// @param ms 延时时间（毫秒），不足一个 tick 的部分向上取整
+/// @return 同 rt_thread_delay
+pub fn rt_thread_mdelay(ms: u32) -> RtErrT {
+    rt_thread_delay(rt_tick_from_millisecond(ms.min(i32::MAX as u32) as i32))
+}
+
+/// 当前线程睡眠到 *last_wake + period，用于固定周期（相位）的循环
+/// 每次调用后 *last_wake 推进一个周期，线程执行时间与唤醒延迟不会累积为漂移；
+/// 若已错过本次唤醒时刻，则不睡眠，*last_wake 前进到不晚于当前 tick 的最近周期边界（相位不变）
+///
+/// 使用示例：
+/// ```rust
+/// let mut last_wake = rt_tick_get();
+/// loop {
+///     rt_thread_delay_until(&mut last_wake, 1000);
+///     control_step();
+/// }
+/// ```
+/// @param last_wake 上一次唤醒的 tick，首次调用前初始化为 rt_tick_get()
+/// @param period 周期（tick）
+/// @return RT_EOK: 成功
+///         RT_EINTR: 被 rt_thread_resume 或信号提前唤醒
+///         RT_ERROR: 不在线程中调用
+pub fn rt_thread_delay_until(last_wake: &mut u32, period: u32) -> RtErrT {
+    let thread = match rt_thread_self() {
+        Some(thread) => thread,
+        None => return RT_ERROR,
+    };
+
+    // 关中断计算剩余时间并启动延时定时器，避免期间 tick 前进造成误差；开中断后才切换线程
+    let level = rt_hw_interrupt_disable();
+    let current_tick = rt_tick_get();
+    let elapsed = current_tick.wrapping_sub(*last_wake);
+    if elapsed < period {
+        *last_wake = last_wake.wrapping_add(period);
+        let result = thread_delay_start(&thread, period - elapsed);
+        rt_hw_interrupt_enable(level);
+        if result != RT_EOK {
+            return result;
+        }
+        rt_schedule();
+        thread_delay_finish(&thread)
+    } else {
+        // 已错过唤醒时刻，跳过错过的整周期，*last_wake 前进到不晚于当前 tick 的最近周期边界，保持相位
+        let missed = if period == 0 { elapsed } else { elapsed - elapsed % period };
+        *last_wake = last_wake.wrapping_add(missed);
+        rt_hw_interrupt_enable(level);
+        rt_thread_yield()
+    }
+}
+
 
 /// 控制线程
 /// * `thread` 线程对象
@@ -430,9 +885,9 @@ pub fn rt_thread_resume(thread: Arc<RtThread>) -> RtErrT {
 
     let level = rt_hw_interrupt_disable();
     
-    // reset_priority
+    // reset_priority，保留从持有的 mutex 的等待者继承的优先级
     let init_priority = thread.inner.exclusive_access().init_priority.clone();
-    rt_thread_set_priority(thread.clone(), init_priority);
+    rt_thread_set_priority(thread.clone(), _mutex_inherited_priority(&thread, init_priority));
 
     thread.inner.exclusive_access().stat = ThreadState::Ready;
     insert_thread(thread.clone());
@@ -470,20 +925,22 @@ pub fn rt_thread_set_priority(thread: Arc<RtThread>,mut priority: u8) -> RtErrT
     if priority > RT_THREAD_PRIORITY_MAX - 1 {// 饱和处理
         priority = RT_THREAD_PRIORITY_MAX - 1;
     }
-    let mut inner = thread.inner.exclusive_access();
-    // hprintln!("rt_thread_set_priority: get inner");
-    inner.current_priority = priority;
     let level = rt_hw_interrupt_disable();
-    if inner.stat.get_stat() == (ThreadState::Ready as u8) {// 如果线程在就绪队列中，则将其从就绪队列中移除再插入
+    let stat = thread.inner.exclusive_access().stat.get_stat();
+    if stat == (ThreadState::Ready as u8) {// 如果线程在就绪队列中，则将其从就绪队列中移除再插入
+        // 注意：必须先按旧优先级移除，且移除/插入期间不能持有inner的借用（两者都会访问inner）
         let _ = remove_thread(thread.clone());
-        inner.current_priority = priority;
-        if cfg!(feature = "full_ffs") {
-            let number = priority >> 3;
-            inner.number_mask = 1 << number;
-            inner.high_mask = 1 << (priority & 0x07);
-        }
-        else {
-            inner.number_mask = 1 << priority;
+        {
+            let mut inner = thread.inner.exclusive_access();
+            inner.current_priority = priority;
+            if cfg!(feature = "full_ffs") {
+                let number = priority >> 3;
+                inner.number_mask = 1 << number;
+                inner.high_mask = 1 << (priority & 0x07);
+            }
+            else {
+                inner.number_mask = 1 << priority;
+            }
         }
         insert_thread(thread.clone());
     }
@@ -494,6 +951,7 @@ pub fn rt_thread_set_priority(thread: Arc<RtThread>,mut priority: u8) -> RtErrT
     //     hprintln!("rt_thread_set_priority: running done");
     // }
     else {// 如果线程不在就绪队列中，则直接设置优先级
+        let mut inner = thread.inner.exclusive_access();
         inner.current_priority = priority;
         if cfg!(feature = "full_ffs") {
             let number = priority >> 3;
@@ -538,4 +996,51 @@ pub fn rt_thread_set_init_priority(thread: Arc<RtThread>, priority: u8) -> RtErr
     let mut inner = thread.inner.exclusive_access();
     inner.init_priority = priority;
     RT_EOK
-}
\ No newline at end of file
+}
+
+/// 线程状态名称
+fn thread_stat_name(stat: u8) -> &'static str {
+    match stat {
+        x if x == ThreadState::Init as u8 => "init",
+        x if x == ThreadState::Ready as u8 => "ready",
+        x if x == ThreadState::Suspend as u8 => "suspend",
+        x if x == ThreadState::Running as u8 => "running",
+        x if x == ThreadState::Close as u8 => "close",
+        _ => "unknown",
+    }
+}
+
+/// 打印所有线程的信息
+/// 包括线程名、状态、优先级、栈大小、栈最大使用量和剩余时间片
+/// 不会因借用冲突而 panic，可在 HardFault 中调用：被打断时正被访问的线程列表或线程显示为 <busy>
+pub fn list_thread() {
+    hprintln!("thread           pri  status   stack size  max used      left tick");
+    hprintln!("---------------- ---  -------  ----------  ------------  ---------");
+    let list = match RT_THREAD_LIST.try_exclusive_access() {
+        Some(list) => list,
+        None => {
+            hprintln!("<busy>");
+            return;
+        }
+    };
+    for thread in list.iter() {
+        let inner = match thread.inner.try_exclusive_access() {
+            Some(inner) => inner,
+            None => {
+                hprintln!("{:<16} <busy>", thread.thread_name());
+                continue;
+            }
+        };
+        let size = inner.kernel_stack.size();
+        let used = inner.kernel_stack.max_used();
+        let percent = if size == 0 { 0 } else { used * 100 / size };
+        hprintln!("{:<16} {:>3}  {:<7}  0x{:08x}  {:>5} ({:>3}%)  {:>9}",
+            thread.thread_name(),
+            inner.current_priority,
+            thread_stat_name(inner.stat.get_stat()),
+            size,
+            used,
+            percent,
+            inner.remaining_tick);
+    }
+}
@@ -1,6 +1,13 @@
 //! 时钟模块
 //! 
 //! 定义了时钟相关的函数和变量
+//!
+//! 系统时钟为 64 位单调递增计数，不会回绕；32 位的 rt_tick_get 仅用于定时器等按回绕比较的场合。
+//! 基于 fugit 提供 Instant / Duration 类型，时间单位为 tick（1 / RT_TICK_PER_SECOND 秒）。
+//!
+//! 类型：Instant、Duration
+//! 函数：rt_tick_get、rt_tick_get64、rt_tick_set、rt_time_now、rt_tick_catch_up、rt_tick_increase、rt_tick_from_millisecond、
+//! rt_tick_to_ms、rt_tick_to_us、rt_tick_to_ns、rt_tick_get_millisecond
 
 #![warn(unused_imports)]
 
@@ -18,21 +25,53 @@ use cortex_m_semihosting::hprintln;
 
 pub const RT_WAITING_FOREVER: u32 = 0xFFFFFFFF;
 
-/// 定义全局变量：当前时钟，使用RTIntrFreeCell包裹，实现中断安全
+/// 时间点，单位为 tick
+pub type Instant = fugit::Instant<u64, 1, RT_TICK_PER_SECOND>;
+/// 时间间隔，单位为 tick
+pub type Duration = fugit::Duration<u64, 1, RT_TICK_PER_SECOND>;
+
+/// 定义全局变量：当前时钟（64 位），使用RTIntrFreeCell包裹，实现中断安全
+/// 访问期间关中断，因此在线程和中断中读取都不会读到被 SysTick 中断打断的半个值
 lazy_static! {
-    static ref RT_TICK: RTIntrFreeCell<u32> = unsafe { RTIntrFreeCell::new(0) };
+    static ref RT_TICK: RTIntrFreeCell<u64> = unsafe { RTIntrFreeCell::new(0) };
 }
 
-/// 获取当前时钟周期
+/// 获取当前时钟周期的低 32 位
+/// 会回绕，比较时应使用 wrapping_sub；需要单调时间时使用 rt_tick_get64 或 rt_time_now
 pub fn rt_tick_get() -> u32 {
+    *RT_TICK.exclusive_access() as u32
+}
+
+/// 获取当前时钟周期（64 位，单调递增）
+pub fn rt_tick_get64() -> u64 {
     *RT_TICK.exclusive_access()
 }
 
+/// 获取当前时间点
+///
+/// 使用示例：
+/// ```rust
+/// let start = rt_time_now();
+/// // ...
+/// let elapsed: Duration = rt_time_now() - start;
+/// hprintln!("elapsed: {} ms", elapsed.to_millis());
+/// ```
+pub fn rt_time_now() -> Instant {
+    Instant::from_ticks(rt_tick_get64())
+}
+
 /// 设置当前时钟周期
-pub fn rt_tick_set(tick: u32) {
+pub fn rt_tick_set(tick: u64) {
     *RT_TICK.exclusive_access() = tick;
 }
 
+/// 补齐 tickless 睡眠期间经过的时钟周期
+/// 调用者需保证期间没有定时器超时（见 rt_tickless_idle）
+/// @param ticks 经过的时钟周期数
+pub fn rt_tick_catch_up(ticks: u32) {
+    *RT_TICK.exclusive_access() += ticks as u64;
+}
+
 /// 时钟中断处理函数
 pub fn rt_tick_increase() {
 
@@ -40,7 +79,7 @@ pub fn rt_tick_increase() {
 
 
     let level = rt_hw_interrupt_disable();
-    *RT_TICK.exclusive_access() +=1 ;
+    *RT_TICK.exclusive_access() += 1;
 
     if let Some(thread) = rt_thread_self() {
         thread.inner.exclusive_access().remaining_tick -= 1;
@@ -60,38 +99,41 @@ pub fn rt_tick_increase() {
     // hprintln!("Current tick: {}", rt_tick_get());
 }
 
-/// 将毫秒转换为时钟周期
+/// 将毫秒转换为时钟周期，不足一个 tick 的部分向上取整
+/// 定时器按 32 位回绕比较，结果最大为 i32::MAX
+/// @param ms 毫秒数，小于0表示永久等待
+/// @return 时钟周期数，ms 小于0时返回 RT_WAITING_FOREVER
 pub fn rt_tick_from_millisecond(ms: i32) -> u32 {
     if ms < 0 {
         RT_WAITING_FOREVER
     } else {
-        let tick = RT_TICK_PER_SECOND * (ms as u32 / 1000);
-        let tick = tick + (RT_TICK_PER_SECOND * (ms as u32 % 1000) + 999) / 1000;
-        tick
+        let tick = (ms as u64 * RT_TICK_PER_SECOND as u64).div_ceil(1000);
+        tick.min(i32::MAX as u64) as u32
     }
 }
 
+/// 按 value * nom / RT_TICK_PER_SECOND 转换，中间结果使用 u128 避免溢出
+fn tick_scale(value: u64, nom: u64) -> u64 {
+    (value as u128 * nom as u128 / RT_TICK_PER_SECOND as u128) as u64
+}
+
 /// 将tick数转换为毫秒
 pub fn rt_tick_to_ms(tick: u64) -> u64 {
-    (tick * 1000) / RT_TICK_PER_SECOND as u64
+    tick_scale(tick, 1000)
 }
 
 
 /// 将tick数转换为微秒
-pub fn rt_tick_to_us(tick: u32) -> u64 {
-    (tick as u64 * 1000000)  / RT_TICK_PER_SECOND as u64
+pub fn rt_tick_to_us(tick: u64) -> u64 {
+    tick_scale(tick, 1000000)
 }
 
-pub fn rt_tick_to_ns(tick: u32) -> u64 {
-    (tick as u64 * 1000000000)  / RT_TICK_PER_SECOND as u64
+/// 将tick数转换为纳秒
+pub fn rt_tick_to_ns(tick: u64) -> u64 {
+    tick_scale(tick, 1000000000)
 }
 
-/// 获取自启动以来经过的毫秒数
+/// 获取自启动以来经过的毫秒数（32 位，约 49 天回绕）
 pub fn rt_tick_get_millisecond() -> u32 {
-    if 1000 % RT_TICK_PER_SECOND == 0 {
-        rt_tick_get() * (1000 / RT_TICK_PER_SECOND)
-    } else {
-        // 错误情况，直接返回0
-        0
-    }
+    rt_tick_to_ms(rt_tick_get64()) as u32
 }
\ No newline at end of file
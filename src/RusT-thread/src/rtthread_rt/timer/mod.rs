@@ -7,7 +7,11 @@
 
 pub mod timer;
 pub mod clock;
+pub mod tickless;
+pub mod skiplist;
 
 pub use self::timer::*;
 pub use self::clock::*;
+pub use self::skiplist::TimerSkipList;
+pub use self::tickless::{rt_tickless_init, rt_tickless_idle};
 
@@ -0,0 +1,187 @@
+//! 定时器跳表
+//!
+//! 按超时时间（64 位 tick，不会回绕）排序的跳表，节点存放在数组中，以下标互相链接。
+//! 每一层都是以表头（下标 0）为哨兵的双向循环链表：
+//! - 插入：从最高层向下查找插入位置，期望 O(log n)，不需要锁定其他定时器
+//! - 删除：已知节点下标时直接摘链，O(RT_TIMER_SKIP_LIST_LEVEL)
+//! - 最近超时的定时器总在第 0 层表头之后，O(1)
+//!
+//! 相同超时时间的定时器按插入顺序排列。
+//!
+//! 结构体：TimerSkipList
+
+#![warn(unused_imports)]
+
+extern crate alloc;
+use alloc::vec::Vec;
+use crate::rtthread_rt::rtconfig::{RT_TIMER_SKIP_LIST_LEVEL, RT_TIMER_SKIP_LIST_MASK};
+use crate::rtthread_rt::timer::timer::TimerHandle;
+
+/// 表头（哨兵）的下标
+const HEAD: usize = 0;
+
+/// 跳表节点
+struct SkipNode {
+    /// 定时器，表头与空闲节点为 None
+    timer: Option<TimerHandle>,
+    /// 超时时间（64 位 tick）
+    deadline: u64,
+    /// 节点层数
+    level: usize,
+    /// 各层的后继
+    next: [usize; RT_TIMER_SKIP_LIST_LEVEL],
+    /// 各层的前驱
+    prev: [usize; RT_TIMER_SKIP_LIST_LEVEL],
+}
+
+impl SkipNode {
+    const fn empty() -> Self {
+        Self {
+            timer: None,
+            deadline: 0,
+            level: RT_TIMER_SKIP_LIST_LEVEL,
+            next: [HEAD; RT_TIMER_SKIP_LIST_LEVEL],
+            prev: [HEAD; RT_TIMER_SKIP_LIST_LEVEL],
+        }
+    }
+}
+
+/// 定时器跳表
+pub struct TimerSkipList {
+    /// 节点数组，下标 0 为表头，首次插入时创建
+    nodes: Vec<SkipNode>,
+    /// 可复用的空闲节点下标
+    free: Vec<usize>,
+    /// 定时器数量
+    len: usize,
+    /// 随机层数使用的伪随机数状态（xorshift32）
+    seed: u32,
+}
+
+impl TimerSkipList {
+    /// 创建空跳表
+    pub const fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            free: Vec::new(),
+            len: 0,
+            seed: 0x2545_F491,
+        }
+    }
+
+    /// 定时器数量
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// 是否没有定时器
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// 随机选取新节点的层数：每升一层的概率为 1 / (RT_TIMER_SKIP_LIST_MASK + 1)
+    fn random_level(&mut self) -> usize {
+        let mut x = self.seed;
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.seed = x;
+
+        let shift = RT_TIMER_SKIP_LIST_MASK.count_ones();
+        let mut level = 1;
+        while level < RT_TIMER_SKIP_LIST_LEVEL && x & RT_TIMER_SKIP_LIST_MASK == 0 {
+            level += 1;
+            x >>= shift;
+        }
+        level
+    }
+
+    /// 插入定时器
+    /// @param timer 定时器
+    /// @param deadline 超时时间（64 位 tick）
+    /// @return 节点下标，用于 remove
+    pub fn insert(&mut self, timer: TimerHandle, deadline: u64) -> usize {
+        if self.nodes.is_empty() {
+            self.nodes.push(SkipNode::empty());
+        }
+
+        // 从最高层向下，找到每一层最后一个超时时间不晚于 deadline 的节点
+        let mut update = [HEAD; RT_TIMER_SKIP_LIST_LEVEL];
+        let mut cur = HEAD;
+        for lvl in (0..RT_TIMER_SKIP_LIST_LEVEL).rev() {
+            loop {
+                let next = self.nodes[cur].next[lvl];
+                if next != HEAD && self.nodes[next].deadline <= deadline {
+                    cur = next;
+                } else {
+                    break;
+                }
+            }
+            update[lvl] = cur;
+        }
+
+        let level = self.random_level();
+        let index = match self.free.pop() {
+            Some(index) => index,
+            None => {
+                self.nodes.push(SkipNode::empty());
+                self.nodes.len() - 1
+            }
+        };
+        self.nodes[index].timer = Some(timer);
+        self.nodes[index].deadline = deadline;
+        self.nodes[index].level = level;
+        for (lvl, &prev) in update.iter().enumerate().take(level) {
+            let next = self.nodes[prev].next[lvl];
+            self.nodes[index].next[lvl] = next;
+            self.nodes[index].prev[lvl] = prev;
+            self.nodes[prev].next[lvl] = index;
+            self.nodes[next].prev[lvl] = index;
+        }
+        self.len += 1;
+        index
+    }
+
+    /// 删除节点
+    /// @param index insert 返回的节点下标
+    /// @return 节点中的定时器，下标无效时返回 None
+    pub fn remove(&mut self, index: usize) -> Option<TimerHandle> {
+        if index == HEAD || index >= self.nodes.len() || self.nodes[index].timer.is_none() {
+            return None;
+        }
+        for lvl in 0..self.nodes[index].level {
+            let prev = self.nodes[index].prev[lvl];
+            let next = self.nodes[index].next[lvl];
+            self.nodes[prev].next[lvl] = next;
+            self.nodes[next].prev[lvl] = prev;
+        }
+        self.free.push(index);
+        self.len -= 1;
+        self.nodes[index].timer.take()
+    }
+
+    /// 最近超时的节点
+    /// @return (节点下标, 超时时间)，跳表为空时返回 None
+    pub fn first(&self) -> Option<(usize, u64)> {
+        let first = self.nodes.get(HEAD)?.next[0];
+        if first == HEAD {
+            None
+        } else {
+            Some((first, self.nodes[first].deadline))
+        }
+    }
+
+    /// 节点中的定时器是否为 timer
+    pub fn contains(&self, index: usize, timer: &TimerHandle) -> bool {
+        index != HEAD
+            && self.nodes.get(index)
+                .and_then(|node| node.timer.as_ref())
+                .is_some_and(|t| alloc::sync::Arc::ptr_eq(t, timer))
+    }
+}
+
+impl Default for TimerSkipList {
+    fn default() -> Self {
+        Self::new()
+    }
+}
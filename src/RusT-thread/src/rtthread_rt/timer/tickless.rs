@@ -0,0 +1,106 @@
+//! 低功耗 tickless 空闲模式
+//!
+//! 空闲线程运行时（没有其他就绪线程），根据最近的定时器超时时间重新设置 SysTick，
+//! 执行 `wfi` 进入睡眠，唤醒后补齐睡眠期间经过的 tick，避免每个 tick 都触发中断。
+//!
+//! 睡眠时长受 SysTick 24 位计数器限制，超过上限时分多次睡眠。
+//!
+//! 函数：rt_tickless_init、rt_tickless_idle
+
+#![warn(unused_imports)]
+
+use core::arch::asm;
+use core::sync::atomic::{AtomicU32, Ordering};
+use crate::rtthread_rt::hardware::irq::{rt_hw_interrupt_disable, rt_hw_interrupt_enable};
+use crate::rtthread_rt::timer::clock::{rt_tick_get64, rt_tick_catch_up};
+use crate::rtthread_rt::timer::timer::rt_timer_next_timeout;
+
+// 寄存器地址常量
+const SYST_CSR: *mut u32 = 0xE000E010 as *mut u32;
+const SYST_RVR: *mut u32 = 0xE000E014 as *mut u32;
+const SYST_CVR: *mut u32 = 0xE000E018 as *mut u32;
+
+const SYST_CSR_COUNTFLAG: u32 = 1 << 16;
+/// SysTick 重载值上限（24 位）
+const SYST_RELOAD_MAX: u32 = 0x00FF_FFFF;
+
+/// 每个 tick 对应的 SysTick 计数（时钟周期数），0 表示未初始化
+static CYCLES_PER_TICK: AtomicU32 = AtomicU32::new(0);
+
+/// 初始化 tickless 模式，由 rt_system_timer_init 调用
+/// @param cycles_per_tick 每个 tick 对应的 SysTick 计数
+pub fn rt_tickless_init(cycles_per_tick: u32) {
+    CYCLES_PER_TICK.store(cycles_per_tick, Ordering::Relaxed);
+}
+
+/// 以 reload 为本次计数周期重新装载 SysTick，之后的周期恢复为一个 tick
+/// 需在关中断时调用
+/// @param reload 本次计数周期（时钟周期数）
+fn systick_reload_once(reload: u32, cycles_per_tick: u32) {
+    unsafe {
+        core::ptr::write_volatile(SYST_RVR, reload - 1);
+        // 写 CVR 清零计数器，下一个时钟周期从 RVR 重新装载
+        core::ptr::write_volatile(SYST_CVR, 0);
+        while core::ptr::read_volatile(SYST_CVR) == 0 {}
+        core::ptr::write_volatile(SYST_RVR, cycles_per_tick - 1);
+    }
+}
+
+/// 空闲时进入 tickless 睡眠
+/// 睡眠到最近的定时器超时（或 SysTick 计数上限），期间任意中断都会提前唤醒
+pub fn rt_tickless_idle() {
+    let cycles_per_tick = CYCLES_PER_TICK.load(Ordering::Relaxed);
+    if cycles_per_tick == 0 {
+        return;
+    }
+    let max_ticks = SYST_RELOAD_MAX / cycles_per_tick;
+
+    let level = rt_hw_interrupt_disable();
+    let sleep_ticks = match rt_timer_next_timeout() {
+        Some(deadline) => {
+            let delta = deadline.saturating_sub(rt_tick_get64());
+            if delta <= 1 {
+                // 下一个 tick 就会超时，按正常 tick 处理
+                rt_hw_interrupt_enable(level);
+                return;
+            }
+            delta.min(max_ticks as u64) as u32
+        }
+        None => max_ticks,
+    };
+    if sleep_ticks <= 1 {
+        rt_hw_interrupt_enable(level);
+        return;
+    }
+
+    unsafe {
+        // 当前 tick 剩余的计数加上之后 sleep_ticks - 1 个完整 tick
+        let remain = core::ptr::read_volatile(SYST_CVR);
+        if remain == 0 {
+            // 计数器刚好到 0，SysTick 中断即将到来
+            rt_hw_interrupt_enable(level);
+            return;
+        }
+        let sleep_cycles = remain + (sleep_ticks - 1) * cycles_per_tick;
+        // 读 CSR 清除 COUNTFLAG
+        core::ptr::read_volatile(SYST_CSR);
+        systick_reload_once(sleep_cycles, cycles_per_tick);
+
+        // PRIMASK 置位时 wfi 仍会被挂起的中断唤醒，中断在开中断后才执行
+        asm!("dsb", "wfi", "isb");
+
+        if core::ptr::read_volatile(SYST_CSR) & SYST_CSR_COUNTFLAG != 0 {
+            // 睡满：最后一个 tick 由挂起的 SysTick 中断计入并检查定时器
+            rt_tick_catch_up(sleep_ticks - 1);
+        } else {
+            // 被其他中断提前唤醒：补齐已经过的 tick 边界，剩余部分作为当前 tick
+            // 睡眠开始时当前 tick 已经过了 cycles_per_tick - remain 个计数
+            let elapsed_cycles = sleep_cycles - core::ptr::read_volatile(SYST_CVR) + (cycles_per_tick - remain);
+            let elapsed_ticks = elapsed_cycles / cycles_per_tick;
+            let partial = elapsed_cycles % cycles_per_tick;
+            systick_reload_once(cycles_per_tick - partial, cycles_per_tick);
+            rt_tick_catch_up(elapsed_ticks);
+        }
+    }
+    rt_hw_interrupt_enable(level);
+}
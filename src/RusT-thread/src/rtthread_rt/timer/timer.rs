@@ -2,13 +2,22 @@
 //! 
 //! 本模块实现了RT-Thread的定时器功能
 //! 包括定时器的创建、启动、停止、控制等
+//!
+//! 定时器分为两类：
+//! - 硬定时器：在 SysTick 中断中检查并执行回调，回调中不能阻塞
+//! - 软定时器（RT_TIMER_FLAG_SOFT_TIMER，需开启 RT_USING_TIMER_SOFT）：
+//!   由定时器线程检查并执行回调，回调中可以阻塞、分配内存或使用 IPC
 
 
 extern crate alloc;
 use crate::rtthread_rt::rtdef::RtObject;
-use crate::rtthread_rt::timer::clock::rt_tick_get;
+use crate::rtthread_rt::timer::clock::{rt_tick_get, rt_tick_get64};
+use crate::rtthread_rt::timer::skiplist::TimerSkipList;
+use crate::rtthread_rt::timer::tickless::rt_tickless_init;
 use crate::rtthread_rt::hardware::irq::{rt_hw_interrupt_disable, rt_hw_interrupt_enable};
-use crate::rtthread_rt::rtconfig::RT_TICK_PER_SECOND;
+use crate::rtthread_rt::rtconfig::{RT_TICK_PER_SECOND, RT_USING_TIMER_SOFT, RT_TIMER_THREAD_PRIO, RT_TIMER_THREAD_STACK_SIZE};
+use crate::rtthread_rt::rtdef::ThreadState;
+use crate::rtthread_rt::thread::{RtThread, rt_thread_create, rt_thread_self, rt_thread_suspend, rt_thread_resume, rt_schedule, insert_thread};
 use alloc::vec::Vec;
 use alloc::boxed::Box;
 use alloc::sync::Arc;
@@ -17,11 +26,15 @@ use cortex_m::peripheral::syst::SystClkSource;
 use core::fmt::{self, Display};
 use cortex_m_semihosting::hprintln;
 
-//由于rust中可以使用高级容器：动态数组，所以不需要使用链表（跳表）算法来加速
-//定时器的查找，而可以使用二分查找，所以我们决定把定时器存放在动态数组中
+//激活的定时器按64位超时时间存放在跳表中：插入期望 O(log n)，且比较时不需要锁定其他定时器；
+//每个定时器记录自己所在的跳表节点，停止时可以直接摘除，不需要遍历
 pub const RT_TIMER_FLAG_ACTIVATED: u8 = 0x1;
-// Assuming 0x2 is RT_TIMER_FLAG_PERIODIC based on usage
-const RT_TIMER_FLAG_PERIODIC: u8 = 0x2;
+/// 周期定时器
+pub const RT_TIMER_FLAG_PERIODIC: u8 = 0x2;
+/// 硬定时器：在中断上下文中执行回调（默认）
+pub const RT_TIMER_FLAG_HARD_TIMER: u8 = 0x0;
+/// 软定时器：在定时器线程中执行回调
+pub const RT_TIMER_FLAG_SOFT_TIMER: u8 = 0x4;
 
 
 /// 定时器结构体
@@ -42,6 +55,19 @@ pub struct RtTimer {
     pub timeout_callback: Option<Callable>,
     pub init_tick: u32,
     pub timeout_tick: u32,
+    /// 激活时所在的跳表节点
+    node: Option<TimerNode>,
+    /// 最近一次激活的超时时间（64 位 tick），周期定时器从这里推进下一次超时
+    deadline: u64,
+}
+
+/// 定时器在跳表中的位置
+#[derive(Clone, Copy)]
+struct TimerNode {
+    /// 是否在软定时器跳表中
+    soft: bool,
+    /// 跳表节点下标
+    index: usize,
 }
 
 impl Display for RtTimer {
@@ -72,12 +98,13 @@ impl RtTimer {
         init_tick: u32,
         timeout_tick: u32,
     ) -> Self {
-        let parent_object = RtObject::new(name, obj_type, flag);
         Self {
-            parent: parent_object.clone(),
+            parent: RtObject::init(name, obj_type, flag),
             timeout_callback: timeout_func,
             init_tick,
             timeout_tick,
+            node: None,
+            deadline: 0,
         }
     }
 
@@ -111,8 +138,14 @@ impl Drop for RtTimer {
 pub type TimerHandle = Arc<Mutex<RtTimer>>;
 pub type Callable = Box<dyn FnMut() + Send + Sync + 'static>;
 
-/// 单线程环境下的全局定时器数组，只能通过本文件的接口操作
-static mut TIMERS: Option<Mutex<Vec<TimerHandle>>> = Some(Mutex::new(Vec::new()));
+/// 全局硬定时器跳表，只能通过本文件的接口操作
+static TIMERS: Mutex<TimerSkipList> = Mutex::new(TimerSkipList::new());
+
+/// 软定时器跳表，由定时器线程处理
+static SOFT_TIMERS: Mutex<TimerSkipList> = Mutex::new(TimerSkipList::new());
+
+/// 定时器线程
+static TIMER_THREAD: Mutex<Option<Arc<RtThread>>> = Mutex::new(None);
 
 /// 延迟停止的定时器列表，用于避免在回调函数中直接操作定时器数组
 static mut PENDING_STOP_TIMERS: Option<Mutex<Vec<TimerHandle>>> = Some(Mutex::new(Vec::new()));
@@ -125,27 +158,80 @@ pub fn timer_system_init() {
     }
 }
 
-/// 启动定时器，将其插入到timers数组中，保持timeout_tick升序
-pub fn rt_timer_start(timer: TimerHandle) {
-    let level = rt_hw_interrupt_disable();
+/// 定时器是否由定时器线程处理
+fn is_soft_timer(flag: u8) -> bool {
+    RT_USING_TIMER_SOFT && flag & RT_TIMER_FLAG_SOFT_TIMER != 0
+}
+
+/// 获取定时器跳表
+/// @param soft true: 软定时器跳表，false: 硬定时器跳表
+fn timer_list(soft: bool) -> &'static Mutex<TimerSkipList> {
+    if soft {
+        &SOFT_TIMERS
+    } else {
+        &TIMERS
+    }
+}
+
+/// 以 deadline 为超时时间激活定时器，需在关中断且持有定时器锁时调用
+/// 已在跳表中的定时器先摘除再重新插入，整个过程不会被 SysTick 中断打断
+/// @param timer 定时器句柄
+/// @param timer_locked 已锁定的定时器
+/// @param deadline 超时时间（64 位 tick）
+fn timer_arm(timer: &TimerHandle, timer_locked: &mut RtTimer, deadline: u64) {
+    // 已在跳表中的定时器先摘除
+    if let Some(node) = timer_locked.node.take() {
+        timer_list(node.soft).lock().remove(node.index);
+    }
+    let soft = is_soft_timer(timer_locked.parent.flag);
+    timer_locked.parent.flag |= RT_TIMER_FLAG_ACTIVATED;// 设置定时器激活状态
+    timer_locked.timeout_tick = deadline as u32;// 设置定时器超时时间
+    timer_locked.deadline = deadline;
+    let index = timer_list(soft).lock().insert(timer.clone(), deadline);// 插入跳表
+    timer_locked.node = Some(TimerNode { soft, index });
+}
+
+/// 从当前时刻开始计时，激活定时器，需在关中断且持有定时器锁时调用
+fn timer_arm_from_now(timer: &TimerHandle, timer_locked: &mut RtTimer) {
+    let deadline = rt_tick_get64() + timer_locked.init_tick as u64;
+    timer_arm(timer, timer_locked, deadline);
+}
+
+/// 周期定时器超时后，从上一次超时时间推进一个周期重新激活，回调延迟不会累积
+/// 若已错过若干个周期（例如回调执行过久），跳过这些周期并保持相位
+/// 需在关中断且持有定时器锁时调用
+fn timer_arm_next_period(timer: &TimerHandle, timer_locked: &mut RtTimer) {
+    let period = timer_locked.init_tick as u64;
+    if period == 0 {
+        timer_arm_from_now(timer, timer_locked);
+        return;
+    }
+    let current_tick = rt_tick_get64();
+    let mut deadline = timer_locked.deadline + period;
+    if deadline <= current_tick {
+        deadline += (current_tick - deadline) / period * period + period;
+    }
+    timer_arm(timer, timer_locked, deadline);
+}
+
+/// 取消尚未处理的延迟停止请求，需在关中断时调用
+fn cancel_pending_stop(timer: &TimerHandle) {
     unsafe {
-        if let Some(ref timers_mutex) = TIMERS {
-            let mut timers = timers_mutex.lock();// 获取定时器数组锁
-            let mut timer_locked = timer.lock();// 获取定时器锁
-            timer_locked.parent.flag |= RT_TIMER_FLAG_ACTIVATED;// 设置定时器激活状态
-            timer_locked.timeout_tick = timer_locked.init_tick.wrapping_add(rt_tick_get());// 设置定时器超时时间
-            let timeout_tick = timer_locked.timeout_tick;// 获取定时器超时时间
-            drop(timer_locked); // 释放定时器锁
-
-            // 使用定时器超时时间进行二分查找
-            let pos = timers.binary_search_by(|probe| {
-                 let probe_locked = probe.lock();
-                 probe_locked.timeout_tick.cmp(&timeout_tick)
-            })
-                .unwrap_or_else(|e| e);
-            timers.insert(pos, timer);// 将定时器插入到timers数组中
+        if let Some(ref pending_stop_mutex) = PENDING_STOP_TIMERS {
+            pending_stop_mutex.lock().retain(|t| !Arc::ptr_eq(t, timer));
         }
     }
+}
+
+/// 启动定时器，按超时时间插入到跳表中
+/// 软定时器插入到定时器线程的跳表中；已启动的定时器会从当前时刻重新计时
+/// 之前调用 rt_timer_stop 但尚未生效的停止请求会被取消
+pub fn rt_timer_start(timer: TimerHandle) {
+    let level = rt_hw_interrupt_disable();
+    cancel_pending_stop(&timer);
+    let mut timer_locked = timer.lock();// 获取定时器锁
+    timer_arm_from_now(&timer, &mut timer_locked);
+    drop(timer_locked); // 释放定时器锁
     
     // hprintln!("rt_timer_start at tick: {}", rt_tick_get());
     // // 打印定时器内容
@@ -171,8 +257,12 @@ pub fn rt_timer_stop(timer: &TimerHandle) {
     // 如果是，则添加到延迟停止列表，避免死锁
     unsafe {
         if let Some(ref pending_stop_mutex) = PENDING_STOP_TIMERS {
+            // 定时器线程中调用时，需关中断避免与 SysTick 中断争用锁
+            let level = rt_hw_interrupt_disable();
             let mut pending_stop = pending_stop_mutex.lock();
             pending_stop.push(timer.clone());
+            drop(pending_stop);
+            rt_hw_interrupt_enable(level);
             // hprintln!("rt_timer_stop: added to pending stop list");
             return; // 延迟处理，避免死锁
         }
@@ -182,38 +272,37 @@ pub fn rt_timer_stop(timer: &TimerHandle) {
     rt_timer_stop_impl(timer);
 }
 
+/// 立即停止定时器，不经过延迟停止列表
+/// 不能在该定时器自身的回调中调用
+pub fn rt_timer_stop_sync(timer: &TimerHandle) {
+    let level = rt_hw_interrupt_disable();
+    cancel_pending_stop(timer);
+    rt_timer_stop_impl(timer);
+    rt_hw_interrupt_enable(level);
+}
+
 /// 实际的定时器停止实现
 fn rt_timer_stop_impl(timer: &TimerHandle) {
     let level = rt_hw_interrupt_disable();
     // hprintln!("rt_timer_stop_impl 1");
-    unsafe {
-        if let Some(ref timers_mutex) = TIMERS {
-            // hprintln!("rt_timer_stop_impl 2");
-            let mut timers = timers_mutex.lock();
-            // hprintln!("rt_timer_stop_impl 3");
-            // Need to compare the Arc pointers themselves, not the locked RtTimer
-            if let Some(pos) = timers.iter().position(|t| Arc::ptr_eq(t, timer)) {
-                // hprintln!("rt_timer_stop_impl 4");
-                timers.remove(pos);
-                // hprintln!("rt_timer_stop_impl: remove timer at pos: {}", pos);
-            }
+    let mut timer_ref = timer.lock();
+    // 根据记录的节点直接从跳表中摘除
+    if let Some(node) = timer_ref.node.take() {
+        let mut timers = timer_list(node.soft).lock();
+        if timers.contains(node.index, timer) {
+            timers.remove(node.index);
         }
     }
-    
     // 清除定时器的激活标志，确保定时器被正确停止
-    {
-        // hprintln!("rt_timer_stop_impl 5");
-        let mut timer_ref = timer.lock();
-        // hprintln!("rt_timer_stop_impl 6");
-        timer_ref.parent.flag &= !RT_TIMER_FLAG_ACTIVATED;
-        // hprintln!("rt_timer_stop_impl: clear activated flag");
-    }
+    timer_ref.parent.flag &= !RT_TIMER_FLAG_ACTIVATED;
+    drop(timer_ref);
     // hprintln!("rt_timer_stop_impl 7");
     rt_hw_interrupt_enable(level);
 }
 
 /// 处理延迟停止的定时器
 fn process_pending_stop_timers() {
+    let level = rt_hw_interrupt_disable();
     unsafe {
         if let Some(ref pending_stop_mutex) = PENDING_STOP_TIMERS {
             let mut pending_stop = pending_stop_mutex.lock();
@@ -225,6 +314,7 @@ fn process_pending_stop_timers() {
             }
         }
     }
+    rt_hw_interrupt_enable(level);
 }
 
 /// 定义定时器控制命令的枚举
@@ -239,6 +329,7 @@ pub enum TimerControlCmd<'a> {
     /// 获取初始超时时间（tick 值），结果存入 `&mut u32`
     GetTime(&'a mut u32),
     /// 设置初始超时时间（tick 值），从 `u32` 读取
+    /// 定时器已激活时，从当前时刻起按新的超时时间重新计时
     SetTime(u32),
     /// 设置定时器为单次模式
     SetOneshot,
@@ -247,10 +338,13 @@ pub enum TimerControlCmd<'a> {
     /// 获取定时器的激活状态 (0 或 RT_TIMER_FLAG_ACTIVATED)，结果存入 `&mut u32`
     GetState(&'a mut u32),
     /// 获取距离超时的剩余时间（tick 值），结果存入 `&mut u32`
-    /// 注意：这里返回的是 `timeout_tick`，如果需要真正的"剩余时间"，
-    /// 可能需要根据当前 tick 重新计算。此处保留与原 C 函数行为相似。
+    /// 定时器未激活或已超时时为 0
     GetRemainTime(&'a mut u32),
-    // 根据需要添加其他控制命令
+    /// 获取定时器标志（RT_TIMER_FLAG_ACTIVATED / RT_TIMER_FLAG_PERIODIC / RT_TIMER_FLAG_SOFT_TIMER），结果存入 `&mut u32`
+    GetFlag(&'a mut u32),
+    /// 从当前时刻起重新计时，未激活的定时器会被启动（例如看门狗喂狗）
+    /// 可以在定时器自身的回调中调用
+    Restart,
 }
 
 /// 控制定时器参数和状态，仿照RT-Thread的rt_timer_control实现
@@ -265,8 +359,10 @@ pub fn rt_timer_control(timer: &TimerHandle, cmd: TimerControlCmd) {
         }
         TimerControlCmd::SetTime(new_tick) => {
             timer_ref.init_tick = new_tick;
-            // 如果定时器已激活，修改 init_tick 后通常需要重新计算 timeout_tick
-            // 这里仅修改 init_tick，实际 RTOS 中可能需要重新启动或调整定时器
+            // 已在跳表中的定时器按新的超时时间重新插入
+            if timer_ref.node.is_some() {
+                timer_arm_from_now(timer, &mut timer_ref);
+            }
         }
         TimerControlCmd::SetOneshot => {
             timer_ref.parent.flag &= !RT_TIMER_FLAG_PERIODIC;
@@ -282,18 +378,28 @@ pub fn rt_timer_control(timer: &TimerHandle, cmd: TimerControlCmd) {
             }
         }
         TimerControlCmd::GetRemainTime(result) => {
-             // 根据 RT-Thread 的行为，此处可能需要返回 timeout_tick - current_tick 的差值
-             // 但 u32 减法需要处理回绕。简单返回 timeout_tick 可能是为了适配某种特定用法。
-             // 如果需要真正的剩余时间，应计算 current_tick.wrapping_sub(timer_ref.timeout_tick) 的补码或类似逻辑。
-             *result = timer_ref.timeout_tick;
+            // 按 64 位超时时间计算，已超时时为 0，超出 u32 范围时饱和
+            *result = if timer_ref.node.is_some() {
+                timer_ref.deadline.saturating_sub(rt_tick_get64()).min(u32::MAX as u64) as u32
+            } else {
+                0
+            };
+        }
+        TimerControlCmd::GetFlag(result) => {
+            *result = timer_ref.parent.flag as u32;
+        }
+        TimerControlCmd::Restart => {
+            cancel_pending_stop(timer);
+            timer_arm_from_now(timer, &mut timer_ref);
         }
-        // 如果所有命令都由枚举覆盖，则无需 _ => {} 分支
     }
 
+    drop(timer_ref);
     rt_hw_interrupt_enable(level);
 }
 
-/// 检查所有定时器，处理超时事件
+/// 检查所有硬定时器，处理超时事件
+/// 在 SysTick 中断中调用；若有软定时器到期，则唤醒定时器线程
 pub fn rt_timer_check() { 
     // if rt_tick_get() % 1000 == 0 {
     //     hprintln!("rt_timer_check at tick: {}", rt_tick_get());
@@ -308,61 +414,154 @@ pub fn rt_timer_check() {
     //         }
     //     }
     // }
-    let mut expired_timers: Vec<TimerHandle> = Vec::new();
     let level = rt_hw_interrupt_disable();
-    let current_tick = rt_tick_get();
-    unsafe {
-        if let Some(ref timers_mutex) = TIMERS {
-            let mut timers = timers_mutex.lock();
-            
-            // 找到第一个未过期的定时器位置
-            let mut expired_count = 0;
-            for timer_handle in timers.iter() {
-                let t = timer_handle.lock();
-                let diff = current_tick.wrapping_sub(t.timeout_tick);
-                
-                // 如果diff >= 0，说明定时器已过期
-                if (diff as i32) >= 0 {
-                    expired_count += 1;
-                } else {
-                    // 找到第一个未过期的定时器，停止搜索
-                    break;
-                }
-            }
-            
-            // 只移除已过期的定时器
-            if expired_count > 0 {
-                // hprintln!("expired_count: {}", &expired_count);
-                expired_timers = timers.drain(0..expired_count).collect();
-            }
+    let expired_timers = timer_list_expired(timer_list(false));
+    rt_hw_interrupt_enable(level);
+
+    timer_list_run(expired_timers);
+    
+    // 处理延迟停止的定时器，避免在回调函数中死锁
+    process_pending_stop_timers();
+
+    // 有软定时器到期时唤醒定时器线程
+    if RT_USING_TIMER_SOFT && soft_timer_expired() {
+        let thread = TIMER_THREAD.lock().clone();
+        if let Some(thread) = thread {
+            rt_thread_resume(thread);
         }
     }
+}
+
+/// 检查所有软定时器，处理超时事件，在定时器线程中调用
+pub fn rt_soft_timer_check() {
+    let level = rt_hw_interrupt_disable();
+    let expired_timers = timer_list_expired(timer_list(true));
+    rt_hw_interrupt_enable(level);
+
+    timer_list_run(expired_timers);
+
+    process_pending_stop_timers();
+}
+
+/// 从定时器跳表中取出所有已超时的定时器，需在关中断时调用
+/// @param list 定时器跳表
+/// @return 已超时的定时器，按超时顺序排列
+fn timer_list_expired(list: &Mutex<TimerSkipList>) -> Vec<TimerHandle> {
+    let mut expired_timers: Vec<TimerHandle> = Vec::new();
+    let current_tick = rt_tick_get64();
+    let mut timers = list.lock();
+    while let Some((index, deadline)) = timers.first() {
+        if deadline > current_tick {
+            break;
+        }
+        if let Some(timer) = timers.remove(index) {
+            expired_timers.push(timer);
+        }
+    }
+    drop(timers);
+    for timer in expired_timers.iter() {
+        timer.lock().node = None;
+    }
+    expired_timers
+}
+
+/// 获取最近的定时器超时时间（硬定时器与软定时器中较早者）
+/// @return 超时时的 64 位 tick 值，没有激活的定时器时返回 None
+pub fn rt_timer_next_timeout() -> Option<u64> {
+    let level = rt_hw_interrupt_disable();
+    let next = [timer_list(false), timer_list(true)]
+        .into_iter()
+        .filter_map(|list| list.lock().first().map(|(_, deadline)| deadline))
+        .min();
     rt_hw_interrupt_enable(level);
+    next
+}
 
+/// 执行已超时定时器的回调，并重新启动仍处于激活状态的周期定时器
+fn timer_list_run(expired_timers: Vec<TimerHandle>) {
     for timer_handle in expired_timers {
-        // Lock the timer to trigger the callback and check periodic flag
-        let mut t = timer_handle.lock();
-        (*t).trigger_timeout(); // Trigger the callback which uses captured data
+        // 取出回调后释放定时器锁再执行：软定时器回调可能阻塞，
+        // 期间其他线程或 SysTick 中断仍需锁定该定时器（停止、重新启动等）
+        let level = rt_hw_interrupt_disable();
+        let callback = {
+            let mut t = timer_handle.lock();
+            // 单次定时器超时后不再激活；在回调之前清除，回调中 Restart 会重新设置
+            if t.parent.flag & RT_TIMER_FLAG_PERIODIC == 0 {
+                t.parent.flag &= !RT_TIMER_FLAG_ACTIVATED;
+            }
+            t.timeout_callback.take()
+        };
+        rt_hw_interrupt_enable(level);
 
+        let mut callback = match callback {
+            Some(callback) => callback,
+            None => continue,
+        };
+        callback(); // Trigger the callback which uses captured data
+
+        let level = rt_hw_interrupt_disable();
+        let mut t = timer_handle.lock();
+        // 回调中可能通过 set_timeout_callback 设置了新的回调，此时保留新回调
+        if t.timeout_callback.is_none() {
+            t.timeout_callback = Some(callback);
+        }
         let is_periodic = t.parent.flag & RT_TIMER_FLAG_PERIODIC != 0;
         let is_activated = t.parent.flag & RT_TIMER_FLAG_ACTIVATED != 0;
 
-        // Unlock before potential recursive call to rt_timer_start
+        // If periodic and still active (not stopped within the callback)
+        // 不取消回调中发出的延迟停止请求，停止请求稍后生效；
+        // 回调中已重新启动（已在跳表中）的定时器不再推进
+        if is_periodic && is_activated && t.node.is_none() {
+            timer_arm_next_period(&timer_handle, &mut t);
+        }
         drop(t);
+        rt_hw_interrupt_enable(level);
+    }
+}
 
-        // If periodic and still active (not stopped within the callback)
-        if is_periodic && is_activated {
-             // Re-lock to update flag and re-start
-             let mut t_recheck = timer_handle.lock();
-             // The timer is about to be re-started, so it's considered active for the next cycle
-             // No need to clear RT_TIMER_FLAG_ACTIVATED here, rt_timer_start will set it.
-             drop(t_recheck);
-             rt_timer_start(timer_handle.clone()); // Re-start the periodic timer
+/// 是否有软定时器已超时
+fn soft_timer_expired() -> bool {
+    let level = rt_hw_interrupt_disable();
+    let current_tick = rt_tick_get64();
+    let expired = timer_list(true).lock().first().is_some_and(|(_, deadline)| deadline <= current_tick);
+    rt_hw_interrupt_enable(level);
+    expired
+}
+
+/// 定时器线程入口函数
+/// 执行到期的软定时器回调，没有到期的定时器时挂起，由 rt_timer_check 唤醒
+extern "C" fn rt_thread_timer_entry(_arg: usize) {
+    loop {
+        rt_soft_timer_check();
+
+        let level = rt_hw_interrupt_disable();
+        if !soft_timer_expired() {
+            if let Some(thread) = rt_thread_self() {
+                rt_thread_suspend(thread);
+            }
         }
+        rt_hw_interrupt_enable(level);
+        rt_schedule();
     }
-    
-    // 处理延迟停止的定时器，避免在回调函数中死锁
-    process_pending_stop_timers();
+}
+
+/// 初始化定时器线程（RT_USING_TIMER_SOFT 开启时）
+/// 需在调度器启动前调用
+pub fn rt_system_timer_thread_init() {
+    if !RT_USING_TIMER_SOFT {
+        return;
+    }
+    let thread = rt_thread_create(
+        "timer",
+        rt_thread_timer_entry as usize,
+        0,
+        RT_TIMER_THREAD_STACK_SIZE as usize,
+        RT_TIMER_THREAD_PRIO as u8,
+        10,
+    ).expect("rt_system_timer_thread_init: create timer thread failed");
+    thread.inner.exclusive_access().stat = ThreadState::Ready;
+    insert_thread(thread.clone());
+    *TIMER_THREAD.lock() = Some(thread);
 }
 
 
@@ -375,6 +574,7 @@ pub fn rt_system_timer_init(mut syst: cortex_m::peripheral::SYST, clocks: &stm32
     // 计算 SysTick 的重载值
     // RT_TICK_PER_SECOND should be defined elsewhere, e.g., in rtconfig
     let reload_value = (sys_clk_freq / RT_TICK_PER_SECOND) - 1;
+    rt_tickless_init(reload_value + 1);
 
     // Configure SysTick
     syst.set_reload(reload_value);
@@ -342,15 +342,15 @@ pub fn run_comprehensive_demo() {
     hprintln!("演示中断级别: {}", rt_hw_get_interrupt_level());
     
     // 创建各种演示线程
-    let basic_thread = rt_thread_create("basic_demo", basic_thread_demo as usize, 2*1024, 2, 100);
-    let priority_thread = rt_thread_create("priority_demo", priority_change_demo as usize, 2*1024, 3, 100);
-    let sleep_thread = rt_thread_create("sleep_demo", sleep_demo as usize, 2*1024, 4, 100);
-    let yield_thread = rt_thread_create("yield_demo", yield_demo as usize, 2*1024, 5, 100);
-    let timer_thread = rt_thread_create("timer_demo", timer_demo as usize, 2*1024, 6, 100);
-    let policy_thread = rt_thread_create("policy_demo", scheduling_policy_demo as usize, 2*1024, 7, 100);
-    let interrupt_thread = rt_thread_create("interrupt_demo", interrupt_level_demo as usize, 2*1024, 8, 100);
-    let control_thread = rt_thread_create("thread_control_demo", thread_control_demo as usize, 2*1024, 9, 100);
-    let resume_thread = rt_thread_create("resume_helper", resume_helper_thread as usize, 2*1024, 10, 100);
+    let basic_thread = rt_thread_create("basic_demo", basic_thread_demo as usize, 0, 2*1024, 2, 100).unwrap();
+    let priority_thread = rt_thread_create("priority_demo", priority_change_demo as usize, 0, 2*1024, 3, 100).unwrap();
+    let sleep_thread = rt_thread_create("sleep_demo", sleep_demo as usize, 0, 2*1024, 4, 100).unwrap();
+    let yield_thread = rt_thread_create("yield_demo", yield_demo as usize, 0, 2*1024, 5, 100).unwrap();
+    let timer_thread = rt_thread_create("timer_demo", timer_demo as usize, 0, 2*1024, 6, 100).unwrap();
+    let policy_thread = rt_thread_create("policy_demo", scheduling_policy_demo as usize, 0, 2*1024, 7, 100).unwrap();
+    let interrupt_thread = rt_thread_create("interrupt_demo", interrupt_level_demo as usize, 0, 2*1024, 8, 100).unwrap();
+    let control_thread = rt_thread_create("thread_control_demo", thread_control_demo as usize, 0, 2*1024, 9, 100).unwrap();
+    let resume_thread = rt_thread_create("resume_helper", resume_helper_thread as usize, 0, 2*1024, 10, 100).unwrap();
     
     // 启动所有线程
     let level = rt_hw_interrupt_disable();
@@ -381,7 +381,7 @@ pub fn run_mfq_demo() {
     // 创建多个相同优先级的线程来演示MFQ调度
     let threads: Vec<Arc<RtThread>> = (0..5).map(|i| {
         let name = alloc::format!("mfq_thread_{}", i);
-        rt_thread_create(&name, mfq_demo_thread as usize, 2*1024, 15, 50)
+        rt_thread_create(&name, mfq_demo_thread as usize, 0, 2*1024, 15, 50).unwrap()
     }).collect();
     
     let level = rt_hw_interrupt_disable();
@@ -96,10 +96,10 @@ pub extern "C" fn example_thread_4(arg: usize) -> () {
 pub fn run_example() {
     hprintln!("run_example: level: {}", rt_hw_get_interrupt_level());
     // hprintln!("run_example...");
-    let thread_1 = rt_thread_create("example_thread_1", example_thread_1 as usize, 2*1024, 10, 500);
-    let thread_2 = rt_thread_create("example_thread_2", example_thread_2 as usize, 2*1024, 10, 500);
-    let thread_3 = rt_thread_create("example_thread_3", example_thread_3 as usize, 2*1024, 10, 500);
-    let thread_4 = rt_thread_create("example_thread_4", example_thread_4 as usize, 2*1024, 14, 500);
+    let thread_1 = rt_thread_create("example_thread_1", example_thread_1 as usize, 0, 2*1024, 10, 500).unwrap();
+    let thread_2 = rt_thread_create("example_thread_2", example_thread_2 as usize, 0, 2*1024, 10, 500).unwrap();
+    let thread_3 = rt_thread_create("example_thread_3", example_thread_3 as usize, 0, 2*1024, 10, 500).unwrap();
+    let thread_4 = rt_thread_create("example_thread_4", example_thread_4 as usize, 0, 2*1024, 14, 500).unwrap();
 
     let level = rt_hw_interrupt_disable();
     hprintln!("run_example after disable: level: {}", level);
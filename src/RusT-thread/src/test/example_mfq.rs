@@ -82,10 +82,10 @@ pub extern "C" fn example_thread_4(arg: usize) -> () {
 /// 4. 最后，3，4优先级降低至与4相同，2，3，4交替运行
 pub fn run_example() {
     // hprintln!("run_example...");
-    let thread_1 = rt_thread_create("example_thread_1", example_thread_1 as usize, 2*1024, 2, 500);
-    let thread_2 = rt_thread_create("example_thread_2", example_thread_2 as usize, 2*1024, 1, 500);
-    let thread_3 = rt_thread_create("example_thread_3", example_thread_3 as usize, 2*1024, 5, 500);
-    let thread_4 = rt_thread_create("example_thread_4", example_thread_4 as usize, 2*1024, 6, 500);
+    let thread_1 = rt_thread_create("example_thread_1", example_thread_1 as usize, 0, 2*1024, 2, 500).unwrap();
+    let thread_2 = rt_thread_create("example_thread_2", example_thread_2 as usize, 0, 2*1024, 1, 500).unwrap();
+    let thread_3 = rt_thread_create("example_thread_3", example_thread_3 as usize, 0, 2*1024, 5, 500).unwrap();
+    let thread_4 = rt_thread_create("example_thread_4", example_thread_4 as usize, 0, 2*1024, 6, 500).unwrap();
 
     let level = rt_hw_interrupt_disable();
     set_mfq_scheduling();
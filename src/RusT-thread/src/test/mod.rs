@@ -20,11 +20,23 @@ pub mod switch_time_test;
 pub mod comprehensive_example;
 pub mod test_interruput_latency;
 pub mod thread_creation_test;
+pub mod test_mutex;
+pub mod test_event;
+pub mod test_mailbox;
+pub mod test_mq;
+pub mod test_sem;
+pub mod test_signal;
+pub mod test_mempool;
+pub mod test_memheap;
+pub mod test_stack;
+pub mod test_api;
 
 // #[cfg(feature = "test_timer")]
 pub mod test_timer;
 #[cfg(feature = "test_small_mem")]
 pub mod test_small_mem;
+#[cfg(feature = "slab")]
+pub mod test_slab;
 #[cfg(feature = "test_allocator_compare")]
 pub mod test_allocator_compare;
 pub mod example_mfq;
@@ -42,6 +54,9 @@ pub fn run_all_tests() {
     // test_mem::test_vec();
     // test_mem::test_alloc_dealloc();
     // test_mem::test_box();
+    // test_slab::test_slab_small();
+    // test_slab::test_slab_large();
+    // test_memheap::test_memheap();
 
     // test_thread::test_thread_context_switch();
     // test_thread::test_thread_context_switch_from_to();
@@ -52,4 +67,17 @@ pub fn run_all_tests() {
     // test_interupt::test_interupt();
     // test_interupt::test_RtIntrFreeCell();
     // test_cell::test_cell();
+
+    // test_mutex::run_mutex_test();
+    // test_event::run_event_test();
+    // test_mailbox::run_mailbox_test();
+    // test_mq::run_mq_test();
+    // test_sem::run_sem_test();
+    // test_signal::run_signal_test();
+    // test_signal::run_signal_ipc_test();
+    // test_signal::run_signal_wait_other_test();
+    // test_mempool::run_mempool_test();
+    // test_stack::run_stack_overflow_test();
+    // test_stack::run_stack_usage_test();
+    // test_api::run_api_test();
 }
@@ -426,46 +426,51 @@ pub fn run_performance_test() {
     let generator = rt_thread_create(
         "event_gen", 
         event_generator_entry as usize, 
+        0, 
         2*1024, 
         10, 
         10
-    );
+    ).unwrap();
     
     // 创建高优先级处理器线程
     let high_processor = rt_thread_create(
         "high_proc", 
         high_priority_processor_entry as usize, 
+        0, 
         2*1024, 
         10, 
         20
-    );
+    ).unwrap();
     
     // 创建中优先级处理器线程
     let medium_processor = rt_thread_create(
         "med_proc", 
         medium_priority_processor_entry as usize, 
+        0, 
         2*1024, 
         10, 
         50
-    );
+    ).unwrap();
     
     // 创建低优先级处理器线程
     let low_processor = rt_thread_create(
         "low_proc", 
         low_priority_processor_entry as usize, 
+        0, 
         2*1024, 
         10, 
         100
-    );
+    ).unwrap();
     
     // 创建结果分析器线程 (最低优先级)
     let analyzer = rt_thread_create(
         "analyzer", 
         result_analyzer_entry as usize, 
+        0, 
         2*1024, 
         10, 
         100
-    );
+    ).unwrap();
     
     // 启动所有线程
     hprintln!("性能测试线程已启动");
@@ -60,7 +60,7 @@ pub extern "C" fn thread1() -> () {
     let end = END_TIME.load(Ordering::SeqCst);
     hprintln!("end； {}", end);
     let total_ticks = end - start;
-    let total_time = rt_tick_to_us(total_ticks);
+    let total_time = rt_tick_to_us(total_ticks as u64);
     let switches = SWITCH_COUNT.load(Ordering::SeqCst);
     let avg_time = total_time as f32 / switches as f32;
     
@@ -106,18 +106,20 @@ pub fn test_thread_switch_time() {
     let thread1 = rt_thread_create(
         "switch1",
         thread1 as usize,
+        0,
         2*1024,
         10,  // 相同优先级
         10   
-    );
+    ).unwrap();
     
     let thread2 = rt_thread_create(
         "switch2",
         thread2 as usize,
+        0,
         2*1024,
         10,  // 相同优先级
         10
-    );
+    ).unwrap();
     
     // 保存线程引用到全局变量
     *THREAD_1.exclusive_access() = Some(thread1.clone());
@@ -0,0 +1,119 @@
+//! Result 风格接口测试代码
+//!
+//! 在返回 Result 的函数中使用 `?` 传播错误，并验证 RtError 与 RT_E* 错误码的互相转换
+extern crate alloc;
+
+use crate::rtthread_rt::api;
+use crate::rtthread_rt::rtdef::*;
+use crate::rtthread_rt::ipc::{RtMailbox, Semaphore};
+use crate::rtthread_rt::mem::RT_MEMHEAP_TAG_SRAM;
+use cortex_m_semihosting::hprintln;
+use alloc::sync::Arc;
+
+/// 小内存管理测试使用的内存区域
+static mut API_SMEM_REGION: [u32; 256] = [0; 256];
+
+/// 生产者：通过邮箱发送数据，出错时由 `?` 直接返回
+fn api_producer() -> Result<(), RtError> {
+    let mb = api::ipc::mb_create("api_mb", 4)?;
+    let sem = api::ipc::sem_create("api_sem", 0)?;
+
+    let consumer_mb = mb.clone();
+    let consumer_sem = sem.clone();
+    api::thread::spawn("api_cons", 2 * 1024, 11, 100, move || {
+        let result = api_consumer(consumer_mb, consumer_sem);
+        assert!(result.is_ok(), "api_consumer: {:?}", result);
+    })?;
+
+    for value in 1..=3 {
+        api::ipc::mb_send(mb.clone(), value)?;
+    }
+    // 等待消费者完成
+    api::ipc::sem_take(sem.clone(), 1000)?;
+
+    // 空邮箱不等待接收，应返回 Timeout
+    let result = api::ipc::mb_recv(mb.clone(), RT_WAITING_NO);
+    assert!(result == Err(RtError::Timeout), "空邮箱接收应超时: {:?}", result);
+    assert!(rt_result_code(&result) == RT_ETIMEOUT, "超时错误码错误");
+
+    api::ipc::mb_delete(mb)?;
+    api::ipc::sem_delete(sem)?;
+    Ok(())
+}
+
+/// 消费者：接收邮箱数据并求和，完成后释放信号量
+fn api_consumer(mb: Arc<RtMailbox>, sem: Arc<Semaphore>) -> Result<(), RtError> {
+    let mut sum = 0;
+    for _ in 0..3 {
+        sum += api::ipc::mb_recv(mb.clone(), RT_WAITING_FOREVER)?;
+    }
+    assert!(sum == 6, "api_consumer: sum {}", sum);
+    api::ipc::sem_release(sem)
+}
+
+/// 定时器接口：超时后的单次定时器不再处于激活状态，stop 与 remain_time 返回错误
+fn api_timer() -> Result<(), RtError> {
+    let timer = api::timer::create("api_timer", 0x0, 10, || {})?;
+    api::timer::start(timer.clone())?;
+    assert!(api::timer::remain_time(&timer)? <= 10, "剩余时间错误");
+    api::thread::sleep(100)?;
+    assert!(api::timer::stop(&timer) == Err(RtError::Error), "超时后的单次定时器 stop 应返回错误");
+    assert!(api::timer::remain_time(&timer) == Err(RtError::Error), "超时后的单次定时器 remain_time 应返回错误");
+    Ok(())
+}
+
+/// 内存接口：分配、调整大小与释放
+fn api_mem() -> Result<(), RtError> {
+    let m = api::mem::smem_init("api_smem", &raw mut API_SMEM_REGION as *mut u8, 1024)?;
+    let block = api::mem::smem_alloc(m, 32)?;
+    let block = api::mem::smem_realloc(m, block, 128)?;
+    assert!(api::mem::smem_realloc(m, block, 0) == Err(RtError::InvalidArgument), "新大小为0未被拒绝");
+    api::mem::smem_free(block);
+    assert!(api::mem::smem_alloc(m, 4096) == Err(RtError::NoMemory), "超过区域大小的分配应失败");
+
+    let block = api::mem::memheap_alloc_tag(RT_MEMHEAP_TAG_SRAM, 64)?;
+    api::mem::memheap_free(block);
+    Ok(())
+}
+
+/// 运行 Result 风格接口测试
+pub fn run_api_test() {
+    hprintln!("开始 Result 接口测试...");
+
+    // 错误码与 RtError 互相转换
+    let errors = [
+        RtError::Ok,
+        RtError::Error,
+        RtError::Timeout,
+        RtError::Full,
+        RtError::Empty,
+        RtError::NoMemory,
+        RtError::NoSystem,
+        RtError::Busy,
+        RtError::IoError,
+        RtError::Interrupted,
+        RtError::InvalidArgument,
+    ];
+    for error in errors {
+        assert!(RtError::from_code(error.code()) == error, "{:?} 错误码转换错误", error);
+    }
+    assert!(RtError::from_code(RT_EFULL) == RtError::Full, "RT_EFULL 转换错误");
+    assert!(RtError::from_code(-RT_EBUSY) == RtError::Error, "-RT_EBUSY 应视为未知错误码");
+    assert!(RtError::from_code(RT_EINVAL + 1) == RtError::Error, "未知错误码应视为 RtError::Error");
+    assert!(RtError::NoMemory.code() == RT_ENOMEM, "RtError::NoMemory 错误码错误");
+
+    let result = api_mem();
+    assert!(result.is_ok(), "api_mem: {:?}", result);
+    let result = api::thread::spawn("api_timer", 2 * 1024, 10, 100, || {
+        let result = api_timer();
+        assert!(result.is_ok(), "api_timer: {:?}", result);
+    });
+    assert!(result.is_ok(), "定时器接口测试线程创建失败");
+
+    let result = api::thread::spawn("api_prod", 2 * 1024, 10, 100, || {
+        let result = api_producer();
+        assert!(result.is_ok(), "api_producer: {:?}", result);
+        hprintln!("Result 接口测试通过");
+    });
+    assert!(result.is_ok(), "Result 接口测试线程创建失败");
+}
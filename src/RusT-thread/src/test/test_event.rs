@@ -0,0 +1,65 @@
+//! 事件集测试代码
+//!
+//! 一个发送线程依次发送传感器就绪事件，
+//! 两个接收线程分别以 AND 和 OR 方式等待事件
+extern crate alloc;
+
+use crate::rtthread_rt::thread::*;
+use crate::rtthread_rt::hardware::*;
+use crate::rtthread_rt::ipc::*;
+use crate::rtthread_rt::rtdef::*;
+use cortex_m_semihosting::hprintln;
+use lazy_static::lazy_static;
+use alloc::sync::Arc;
+
+const EVENT_SENSOR_A: u32 = 1 << 0;
+const EVENT_SENSOR_B: u32 = 1 << 1;
+
+lazy_static! {
+    static ref TEST_EVENT: Arc<RtEvent> = rt_event_create("test_event");
+}
+
+/// 以 AND 方式等待 A、B 两个事件，不清除
+pub extern "C" fn event_and_thread(arg: usize) -> () {
+    let mut recved = 0;
+    let result = rt_event_recv(TEST_EVENT.clone(), EVENT_SENSOR_A | EVENT_SENSOR_B, RT_EVENT_FLAG_AND, RT_WAITING_FOREVER, &mut recved);
+    hprintln!("event_and_thread: result: {}, recved: {:#x} (expect 0x3)", result, recved);
+    rt_thread_delete(rt_thread_self().unwrap());
+}
+
+/// 以 OR 方式等待 A 事件，收到后清除
+pub extern "C" fn event_or_thread(arg: usize) -> () {
+    let mut recved = 0;
+    let result = rt_event_recv(TEST_EVENT.clone(), EVENT_SENSOR_A, RT_EVENT_FLAG_OR | RT_EVENT_FLAG_CLEAR, RT_WAITING_FOREVER, &mut recved);
+    hprintln!("event_or_thread: result: {}, recved: {:#x} (expect 0x1)", result, recved);
+    // 超时接收
+    let result = rt_event_recv(TEST_EVENT.clone(), EVENT_SENSOR_A, RT_EVENT_FLAG_OR, 100, &mut recved);
+    hprintln!("event_or_thread: result: {} (expect RT_ETIMEOUT={})", result, RT_ETIMEOUT);
+    rt_thread_delete(rt_thread_self().unwrap());
+}
+
+/// 发送线程
+pub extern "C" fn event_send_thread(arg: usize) -> () {
+    rt_thread_sleep(rt_thread_self().unwrap(), 500);
+    hprintln!("event_send_thread: send B");
+    rt_event_send(TEST_EVENT.clone(), EVENT_SENSOR_B);
+    rt_thread_sleep(rt_thread_self().unwrap(), 500);
+    hprintln!("event_send_thread: send A");
+    rt_event_send(TEST_EVENT.clone(), EVENT_SENSOR_A);
+    rt_thread_delete(rt_thread_self().unwrap());
+}
+
+/// 运行事件集测试
+pub fn run_event_test() {
+    hprintln!("开始事件集测试...");
+    let and_thread = rt_thread_create("event_and", event_and_thread as usize, 0, 2*1024, 10, 100).unwrap();
+    let or_thread = rt_thread_create("event_or", event_or_thread as usize, 0, 2*1024, 11, 100).unwrap();
+    let send_thread = rt_thread_create("event_send", event_send_thread as usize, 0, 2*1024, 12, 100).unwrap();
+
+    let level = rt_hw_interrupt_disable();
+    rt_thread_startup(and_thread);
+    rt_thread_startup(or_thread);
+    rt_thread_startup(send_thread);
+    rt_hw_interrupt_enable(level);
+    hprintln!("事件集测试线程已启动");
+}
@@ -120,13 +120,13 @@ pub fn run_ipc_test() {
     
     // 创建测试线程
 
-    let thread_1 = rt_thread_create("test_thread_1", test_ipc_thread_1 as usize, 2*1024, 10, 200);
+    let thread_1 = rt_thread_create("test_thread_1", test_ipc_thread_1 as usize, 0, 2*1024, 10, 200).unwrap();
     hprintln!("thread_1: {:?}", thread_1);
-    let thread_2 = rt_thread_create("test_thread_2", test_ipc_thread_2 as usize, 2*1024, 10, 200);
+    let thread_2 = rt_thread_create("test_thread_2", test_ipc_thread_2 as usize, 0, 2*1024, 10, 200).unwrap();
     hprintln!("thread_2: {:?}", thread_2);
-    let thread_3 = rt_thread_create("test_thread_3", test_ipc_thread_3 as usize, 2*1024, 14, 200);
+    let thread_3 = rt_thread_create("test_thread_3", test_ipc_thread_3 as usize, 0, 2*1024, 14, 200).unwrap();
     hprintln!("thread_3: {:?}", thread_3);
-    let thread_4 = rt_thread_create("test_thread_4", test_ipc_thread_4 as usize, 2*1024, 16, 200);
+    let thread_4 = rt_thread_create("test_thread_4", test_ipc_thread_4 as usize, 0, 2*1024, 16, 200).unwrap();
     hprintln!("thread_4: {:?}", thread_4);
 
     // 禁用中断并启动线程
@@ -167,8 +167,8 @@ pub fn test_ipc_queue_operations() {
     let ipc = rt_ipc_init("test_queue", 1);
     
     // 创建测试线程
-    let thread_1 = rt_thread_create("queue_test_1", test_ipc_thread_1 as usize, 1*1024, 10, 1000);
-    let thread_2 = rt_thread_create("queue_test_2", test_ipc_thread_2 as usize, 1*1024, 12, 1000);
+    let thread_1 = rt_thread_create("queue_test_1", test_ipc_thread_1 as usize, 0, 1*1024, 10, 1000).unwrap();
+    let thread_2 = rt_thread_create("queue_test_2", test_ipc_thread_2 as usize, 0, 1*1024, 12, 1000).unwrap();
     
     // 测试挂起线程到队列
     rt_ipc_list_suspend(ipc.clone(), thread_1.clone());
@@ -581,11 +581,11 @@ pub fn run_dining_philosophers_test() {
     
     // 创建5个哲学家线程，传递不同的参数
     let philosophers = [
-        rt_thread_create("philosopher_0", philosopher_thread_0 as usize, 2*1024, 10, 20),
-        rt_thread_create("philosopher_1", philosopher_thread_1 as usize, 2*1024, 10, 20),
-        rt_thread_create("philosopher_2", philosopher_thread_2 as usize, 2*1024, 10, 20),
-        rt_thread_create("philosopher_3", philosopher_thread_3 as usize, 2*1024, 10, 20),
-        rt_thread_create("philosopher_4", philosopher_thread_4 as usize, 2*1024, 10, 20),
+        rt_thread_create("philosopher_0", philosopher_thread_0 as usize, 0, 2*1024, 10, 20).unwrap(),
+        rt_thread_create("philosopher_1", philosopher_thread_1 as usize, 0, 2*1024, 10, 20).unwrap(),
+        rt_thread_create("philosopher_2", philosopher_thread_2 as usize, 0, 2*1024, 10, 20).unwrap(),
+        rt_thread_create("philosopher_3", philosopher_thread_3 as usize, 0, 2*1024, 10, 20).unwrap(),
+        rt_thread_create("philosopher_4", philosopher_thread_4 as usize, 0, 2*1024, 10, 20).unwrap(),
     ];
     
     // 禁用中断并启动所有哲学家线程
@@ -0,0 +1,56 @@
+//! 邮箱测试代码
+//!
+//! 发送线程向容量为2的邮箱连续发送邮件（邮箱满时阻塞），
+//! 接收线程较慢地接收，并测试紧急邮件与超时接收
+extern crate alloc;
+
+use crate::rtthread_rt::thread::*;
+use crate::rtthread_rt::hardware::*;
+use crate::rtthread_rt::ipc::*;
+use crate::rtthread_rt::rtdef::*;
+use cortex_m_semihosting::hprintln;
+use lazy_static::lazy_static;
+use alloc::sync::Arc;
+
+lazy_static! {
+    static ref TEST_MB: Arc<RtMailbox> = rt_mb_create("test_mb", 2).unwrap();
+}
+
+/// 发送线程：发送 1..=4，第3封起会因邮箱满而阻塞
+pub extern "C" fn mb_send_thread(arg: usize) -> () {
+    for value in 1..=4 {
+        let result = rt_mb_send_wait(TEST_MB.clone(), value, RT_WAITING_FOREVER);
+        hprintln!("mb_send_thread: send {} result: {}", value, result);
+    }
+    let result = rt_mb_urgent(TEST_MB.clone(), 100);
+    hprintln!("mb_send_thread: urgent result: {}", result);
+    rt_thread_delete(rt_thread_self().unwrap());
+}
+
+/// 接收线程：每隔一段时间接收一封邮件
+pub extern "C" fn mb_recv_thread(arg: usize) -> () {
+    let mut value = 0;
+    loop {
+        rt_thread_sleep(rt_thread_self().unwrap(), 300);
+        let result = rt_mb_recv(TEST_MB.clone(), &mut value, 200);
+        if result != RT_EOK {
+            hprintln!("mb_recv_thread: recv result: {} (expect RT_ETIMEOUT={})", result, RT_ETIMEOUT);
+            break;
+        }
+        hprintln!("mb_recv_thread: recv {}", value);
+    }
+    rt_thread_delete(rt_thread_self().unwrap());
+}
+
+/// 运行邮箱测试
+pub fn run_mailbox_test() {
+    hprintln!("开始邮箱测试...");
+    let send_thread = rt_thread_create("mb_send", mb_send_thread as usize, 0, 2*1024, 10, 100).unwrap();
+    let recv_thread = rt_thread_create("mb_recv", mb_recv_thread as usize, 0, 2*1024, 11, 100).unwrap();
+
+    let level = rt_hw_interrupt_disable();
+    rt_thread_startup(send_thread);
+    rt_thread_startup(recv_thread);
+    rt_hw_interrupt_enable(level);
+    hprintln!("邮箱测试线程已启动");
+}
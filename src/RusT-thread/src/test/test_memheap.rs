@@ -0,0 +1,42 @@
+//! 多区域内存堆测试代码
+//!
+//! 启动时已从链接符号注册了 SRAM 与 CCMRAM 两个区域，
+//! 测试按标签分配、按顺序分配、区域重叠检查以及释放
+use cortex_m_semihosting::hprintln;
+
+use crate::rtthread_rt::mem::*;
+use crate::rtthread_rt::mem::allocator::heap_range;
+use crate::rtthread_rt::rtdef::*;
+
+/// memory.x 中 RAM 与 CCMRAM 的地址范围
+const RAM_RANGE: core::ops::Range<usize> = 0x2000_0000..0x2002_0000;
+const CCM_RANGE: core::ops::Range<usize> = 0x1000_0000..0x1001_0000;
+
+/// 作为额外区域注册的缓冲区
+static mut EXTRA_REGION: [u8; 1024] = [0; 1024];
+
+pub fn test_memheap() {
+    hprintln!("test_memheap");
+    let result = rt_memheap_init("extraheap", RT_MEMHEAP_TAG_SRAM, &raw mut EXTRA_REGION as *mut u8, 1024);
+    assert!(result == RT_EOK, "注册额外区域失败");
+    // 重复注册同一区域应失败
+    let result = rt_memheap_init("extraheap2", RT_MEMHEAP_TAG_SRAM, &raw mut EXTRA_REGION as *mut u8, 1024);
+    assert!(result == RT_ERROR, "重叠的区域未被拒绝");
+
+    let (heap_start, heap_size) = heap_range();
+    let ccm = rt_memheap_alloc_tag(RT_MEMHEAP_TAG_CCM, 256);
+    assert!(CCM_RANGE.contains(&(ccm as usize)), "CCM 标签分配不在 CCMRAM 中: {:p}", ccm);
+    let sram = rt_memheap_alloc_tag(RT_MEMHEAP_TAG_SRAM, 256);
+    assert!(RAM_RANGE.contains(&(sram as usize)), "SRAM 标签分配不在 RAM 中: {:p}", sram);
+    assert!(!(heap_start..heap_start + heap_size).contains(&(sram as usize)), "SRAM 区域与全局堆重叠: {:p}", sram);
+    // 超过额外区域大小的分配按顺序落到其他区域
+    let big = rt_memheap_alloc(4096);
+    assert!(!big.is_null(), "大块分配失败");
+    rt_memheap_info();
+
+    rt_memheap_free(ccm);
+    rt_memheap_free(sram);
+    rt_memheap_free(big);
+    rt_memheap_info();
+    hprintln!("test_memheap done\n\n\n");
+}
@@ -0,0 +1,63 @@
+//! 内存池测试代码
+//!
+//! 在静态缓冲区上创建只有两个内存块的内存池，
+//! 分配线程耗尽内存块后阻塞等待，释放线程稍后归还内存块
+extern crate alloc;
+
+use crate::rtthread_rt::thread::*;
+use crate::rtthread_rt::hardware::*;
+use crate::rtthread_rt::mem::*;
+use crate::rtthread_rt::rtdef::*;
+use cortex_m_semihosting::hprintln;
+use lazy_static::lazy_static;
+use alloc::sync::Arc;
+use spin::Mutex;
+
+/// 内存池使用的内存区域：2 个 32 字节的块（每块另有 4 字节块头）
+/// 块头按 usize 读写，因此以 u32 数组保证 4 字节对齐
+static mut MP_BUFFER: [u32; 2 * (32 + 4) / 4] = [0; 2 * (32 + 4) / 4];
+
+lazy_static! {
+    static ref TEST_MP: Arc<RtMemPool> = rt_mp_create("test_mp", &raw mut MP_BUFFER as *mut u8, 2 * (32 + 4), 32).unwrap();
+    static ref HELD_BLOCK: Mutex<usize> = Mutex::new(0);
+}
+
+/// 分配线程：分配两个块后内存池耗尽，先限时等待（应超时），再永久等待
+pub extern "C" fn mp_alloc_thread(arg: usize) -> () {
+    let first = rt_mp_alloc(TEST_MP.clone(), RT_WAITING_NO);
+    let second = rt_mp_alloc(TEST_MP.clone(), RT_WAITING_NO);
+    hprintln!("mp_alloc_thread: first: {:?}, second: {:?}", first, second);
+    *HELD_BLOCK.lock() = first as usize;
+
+    let block = rt_mp_alloc(TEST_MP.clone(), 100);
+    hprintln!("mp_alloc_thread: timed alloc: {:?} (expect null)", block);
+    let block = rt_mp_alloc(TEST_MP.clone(), RT_WAITING_FOREVER);
+    hprintln!("mp_alloc_thread: alloc after free: {:?} (expect {:?})", block, first);
+
+    rt_mp_free(block);
+    rt_mp_free(second);
+    hprintln!("mp_alloc_thread: free count: {}", TEST_MP.inner.exclusive_access().block_free_count);
+    rt_thread_delete(rt_thread_self().unwrap());
+}
+
+/// 释放线程：稍后归还分配线程持有的第一个块
+pub extern "C" fn mp_free_thread(arg: usize) -> () {
+    rt_thread_sleep(rt_thread_self().unwrap(), 500);
+    let block = *HELD_BLOCK.lock() as *mut u8;
+    hprintln!("mp_free_thread: free {:?}", block);
+    rt_mp_free(block);
+    rt_thread_delete(rt_thread_self().unwrap());
+}
+
+/// 运行内存池测试
+pub fn run_mempool_test() {
+    hprintln!("开始内存池测试...");
+    let alloc_thread = rt_thread_create("mp_alloc", mp_alloc_thread as usize, 0, 2*1024, 10, 100).unwrap();
+    let free_thread = rt_thread_create("mp_free", mp_free_thread as usize, 0, 2*1024, 11, 100).unwrap();
+
+    let level = rt_hw_interrupt_disable();
+    rt_thread_startup(alloc_thread);
+    rt_thread_startup(free_thread);
+    rt_hw_interrupt_enable(level);
+    hprintln!("内存池测试线程已启动");
+}
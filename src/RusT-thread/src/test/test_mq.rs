@@ -0,0 +1,61 @@
+//! 消息队列测试代码
+//!
+//! 发送线程向容量为2的消息队列连续发送字符串消息（队列满时阻塞），
+//! 接收线程较慢地接收，并测试紧急消息、超长消息与超时接收
+extern crate alloc;
+
+use crate::rtthread_rt::thread::*;
+use crate::rtthread_rt::hardware::*;
+use crate::rtthread_rt::ipc::*;
+use crate::rtthread_rt::rtdef::*;
+use cortex_m_semihosting::hprintln;
+use lazy_static::lazy_static;
+use alloc::sync::Arc;
+
+lazy_static! {
+    static ref TEST_MQ: Arc<RtMessageQueue> = rt_mq_create("test_mq", 16, 2, RT_IPC_FLAG_FIFO).unwrap();
+}
+
+/// 发送线程：发送4条消息，第3条起会因队列满而阻塞
+pub extern "C" fn mq_send_thread(arg: usize) -> () {
+    let messages: [&[u8]; 4] = [b"msg 1", b"msg 2", b"msg 3", b"msg 4"];
+    for msg in messages.iter() {
+        let result = rt_mq_send_wait(TEST_MQ.clone(), msg, RT_WAITING_FOREVER);
+        hprintln!("mq_send_thread: send {:?} result: {}", core::str::from_utf8(msg).unwrap(), result);
+    }
+    // 超过 msg_size 的消息应被拒绝
+    let result = rt_mq_send(TEST_MQ.clone(), &[0u8; 32]);
+    hprintln!("mq_send_thread: oversize send result: {} (expect RT_ERROR={})", result, RT_ERROR);
+    let result = rt_mq_urgent(TEST_MQ.clone(), b"urgent");
+    hprintln!("mq_send_thread: urgent result: {}", result);
+    rt_thread_delete(rt_thread_self().unwrap());
+}
+
+/// 接收线程：每隔一段时间接收一条消息
+pub extern "C" fn mq_recv_thread(arg: usize) -> () {
+    let mut buffer = [0u8; 16];
+    let mut len = 0;
+    loop {
+        rt_thread_sleep(rt_thread_self().unwrap(), 300);
+        let result = rt_mq_recv(TEST_MQ.clone(), &mut buffer, 200, &mut len);
+        if result != RT_EOK {
+            hprintln!("mq_recv_thread: recv result: {} (expect RT_ETIMEOUT={})", result, RT_ETIMEOUT);
+            break;
+        }
+        hprintln!("mq_recv_thread: recv {:?}", core::str::from_utf8(&buffer[..len]).unwrap());
+    }
+    rt_thread_delete(rt_thread_self().unwrap());
+}
+
+/// 运行消息队列测试
+pub fn run_mq_test() {
+    hprintln!("开始消息队列测试...");
+    let send_thread = rt_thread_create("mq_send", mq_send_thread as usize, 0, 2*1024, 10, 100).unwrap();
+    let recv_thread = rt_thread_create("mq_recv", mq_recv_thread as usize, 0, 2*1024, 11, 100).unwrap();
+
+    let level = rt_hw_interrupt_disable();
+    rt_thread_startup(send_thread);
+    rt_thread_startup(recv_thread);
+    rt_hw_interrupt_enable(level);
+    hprintln!("消息队列测试线程已启动");
+}
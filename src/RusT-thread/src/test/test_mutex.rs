@@ -0,0 +1,77 @@
+//! 互斥量测试代码
+//!
+//! 测试互斥量的递归持有与优先级继承
+//! 低优先级线程先持有互斥量，高优先级线程随后阻塞在互斥量上，
+//! 此时低优先级线程的优先级应被提升到高优先级线程的优先级，释放后恢复
+extern crate alloc;
+
+use crate::rtthread_rt::thread::*;
+use crate::rtthread_rt::timer::rt_tick_get;
+use crate::rtthread_rt::hardware::*;
+use crate::rtthread_rt::ipc::*;
+use crate::rtthread_rt::rtdef::*;
+use cortex_m_semihosting::hprintln;
+use lazy_static::lazy_static;
+use alloc::sync::Arc;
+
+lazy_static! {
+    static ref TEST_MUTEX: Arc<RtMutex> = rt_mutex_create("test_mutex");
+}
+
+/// 低优先级线程：持有互斥量一段时间
+pub extern "C" fn mutex_low_thread(arg: usize) -> () {
+    hprintln!("mutex_low_thread: take");
+    rt_mutex_take(TEST_MUTEX.clone(), RT_WAITING_FOREVER);
+    // 递归获取
+    if rt_mutex_take(TEST_MUTEX.clone(), RT_WAITING_FOREVER) == RT_EOK {
+        hprintln!("mutex_low_thread: recursive take ok, hold: {}", TEST_MUTEX.inner.exclusive_access().hold);
+    }
+    let tic = rt_tick_get();
+    while rt_tick_get() - tic < 3000 {
+        cortex_m::asm::nop();
+    }
+    // 睡眠后被唤醒时不应丢失继承的优先级
+    rt_thread_sleep(rt_thread_self().unwrap(), 100);
+    let priority = rt_thread_self().unwrap().inner.exclusive_access().current_priority;
+    hprintln!("mutex_low_thread: priority before release: {} (expect 10)", priority);
+    assert!(priority == 10, "唤醒后丢失了继承的优先级");
+    rt_mutex_release(TEST_MUTEX.clone());
+    rt_mutex_release(TEST_MUTEX.clone());
+    let priority = rt_thread_self().unwrap().inner.exclusive_access().current_priority;
+    hprintln!("mutex_low_thread: priority after release: {} (expect 20)", priority);
+    rt_thread_delete(rt_thread_self().unwrap());
+}
+
+/// 高优先级线程：稍后尝试获取互斥量
+pub extern "C" fn mutex_high_thread(arg: usize) -> () {
+    rt_thread_sleep(rt_thread_self().unwrap(), 1000);
+    hprintln!("mutex_high_thread: take");
+    if rt_mutex_take(TEST_MUTEX.clone(), RT_WAITING_FOREVER) == RT_EOK {
+        hprintln!("mutex_high_thread: got mutex");
+        rt_mutex_release(TEST_MUTEX.clone());
+    }
+    rt_thread_delete(rt_thread_self().unwrap());
+}
+
+/// 超时线程：互斥量被占用时限时等待
+pub extern "C" fn mutex_timeout_thread(arg: usize) -> () {
+    rt_thread_sleep(rt_thread_self().unwrap(), 500);
+    let result = rt_mutex_take(TEST_MUTEX.clone(), 200);
+    hprintln!("mutex_timeout_thread: take result: {} (expect RT_ETIMEOUT={})", result, RT_ETIMEOUT);
+    rt_thread_delete(rt_thread_self().unwrap());
+}
+
+/// 运行互斥量测试
+pub fn run_mutex_test() {
+    hprintln!("开始互斥量测试...");
+    let low = rt_thread_create("mutex_low", mutex_low_thread as usize, 0, 2*1024, 20, 100).unwrap();
+    let high = rt_thread_create("mutex_high", mutex_high_thread as usize, 0, 2*1024, 10, 100).unwrap();
+    let timeout = rt_thread_create("mutex_to", mutex_timeout_thread as usize, 0, 2*1024, 15, 100).unwrap();
+
+    let level = rt_hw_interrupt_disable();
+    rt_thread_startup(low);
+    rt_thread_startup(high);
+    rt_thread_startup(timeout);
+    rt_hw_interrupt_enable(level);
+    hprintln!("互斥量测试线程已启动");
+}
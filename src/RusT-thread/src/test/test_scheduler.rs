@@ -34,16 +34,16 @@ pub extern "C" fn thread2_enter(arg: usize) -> () {
 // test1： 线程插入与删除
 pub fn test_insert_thread() {
     // insert thread
-    let thread1 = rt_thread_create("thread1",thread1_enter as usize, 1024,4,1000);
+    let thread1 = rt_thread_create("thread1",thread1_enter as usize, 0, 1024,4,1000).unwrap();
     // 设置线程状态为Ready后再插入
     thread1.inner.exclusive_access().stat = ThreadState::Ready;
     insert_thread(thread1.clone());
     
-    let thread2 = rt_thread_create("thread2",thread1_enter as usize, 1024,4,1000);
+    let thread2 = rt_thread_create("thread2",thread1_enter as usize, 0, 1024,4,1000).unwrap();
     thread2.inner.exclusive_access().stat = ThreadState::Ready;
     insert_thread(thread2);
     
-    let thread3 = rt_thread_create("thread3",thread1_enter as usize, 1024,11,1000);
+    let thread3 = rt_thread_create("thread3",thread1_enter as usize, 0, 1024,11,1000).unwrap();
     thread3.inner.exclusive_access().stat = ThreadState::Ready;
     insert_thread(thread3);
     
@@ -75,11 +75,11 @@ fn look_at_priority_table(){
 // test2: 调度开始
 pub fn test_schedule_start() {
     // insert thread
-    let thread1 = rt_thread_create("thread1",thread1_enter as usize, 1024,1,1000);
+    let thread1 = rt_thread_create("thread1",thread1_enter as usize, 0, 1024,1,1000).unwrap();
     insert_thread(thread1.clone());
-    let thread2 = rt_thread_create("thread2",thread2_enter as usize, 1024,1,1000);
+    let thread2 = rt_thread_create("thread2",thread2_enter as usize, 0, 1024,1,1000).unwrap();
     insert_thread(thread2);
-    let thread3 = rt_thread_create("thread3",thread2_enter as usize, 1024,11,1000);
+    let thread3 = rt_thread_create("thread3",thread2_enter as usize, 0, 1024,11,1000).unwrap();
     insert_thread(thread3);
 
     // start schedule
@@ -89,11 +89,11 @@ pub fn test_schedule_start() {
 
 
 pub fn test_schedule(){
-    let thread1 = rt_thread_create("thread1",thread1_enter as usize, 1024,1,1000);
+    let thread1 = rt_thread_create("thread1",thread1_enter as usize, 0, 1024,1,1000).unwrap();
     insert_thread(thread1.clone());
-    let thread2 = rt_thread_create("thread2",thread2_enter as usize, 1024,1,1000);
+    let thread2 = rt_thread_create("thread2",thread2_enter as usize, 0, 1024,1,1000).unwrap();
     insert_thread(thread2);
-    let thread3 = rt_thread_create("thread3",thread2_enter as usize, 1024,11,1000);
+    let thread3 = rt_thread_create("thread3",thread2_enter as usize, 0, 1024,11,1000).unwrap();
     insert_thread(thread3);
 
     hprintln!("A");
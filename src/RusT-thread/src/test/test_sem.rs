@@ -0,0 +1,51 @@
+//! 信号量测试代码
+//!
+//! 测试信号量的限时获取、释放唤醒以及删除时唤醒等待线程
+extern crate alloc;
+
+use crate::rtthread_rt::thread::*;
+use crate::rtthread_rt::hardware::*;
+use crate::rtthread_rt::ipc::*;
+use crate::rtthread_rt::rtdef::*;
+use cortex_m_semihosting::hprintln;
+use lazy_static::lazy_static;
+use alloc::sync::Arc;
+
+lazy_static! {
+    static ref TEST_SEM: Arc<Semaphore> = rt_sem_create("test_sem", 0).unwrap();
+}
+
+/// 等待线程：先限时等待（应超时），再永久等待（由释放线程唤醒），最后等待删除
+pub extern "C" fn sem_take_thread(arg: usize) -> () {
+    let result = rt_sem_take(TEST_SEM.clone(), 200);
+    hprintln!("sem_take_thread: timed take result: {} (expect RT_ETIMEOUT={})", result, RT_ETIMEOUT);
+    let result = rt_sem_take(TEST_SEM.clone(), RT_WAITING_FOREVER);
+    hprintln!("sem_take_thread: take result: {} (expect RT_EOK={})", result, RT_EOK);
+    let result = rt_sem_take(TEST_SEM.clone(), RT_WAITING_FOREVER);
+    hprintln!("sem_take_thread: take after delete result: {} (expect RT_ERROR={})", result, RT_ERROR);
+    rt_thread_delete(rt_thread_self().unwrap());
+}
+
+/// 释放线程：释放一次信号量，随后删除信号量
+pub extern "C" fn sem_release_thread(arg: usize) -> () {
+    rt_thread_sleep(rt_thread_self().unwrap(), 500);
+    hprintln!("sem_release_thread: release");
+    rt_sem_release(TEST_SEM.clone());
+    rt_thread_sleep(rt_thread_self().unwrap(), 500);
+    hprintln!("sem_release_thread: delete");
+    rt_sem_delete(TEST_SEM.clone());
+    rt_thread_delete(rt_thread_self().unwrap());
+}
+
+/// 运行信号量测试
+pub fn run_sem_test() {
+    hprintln!("开始信号量测试...");
+    let take_thread = rt_thread_create("sem_take", sem_take_thread as usize, 0, 2*1024, 10, 100).unwrap();
+    let release_thread = rt_thread_create("sem_release", sem_release_thread as usize, 0, 2*1024, 11, 100).unwrap();
+
+    let level = rt_hw_interrupt_disable();
+    rt_thread_startup(take_thread);
+    rt_thread_startup(release_thread);
+    rt_hw_interrupt_enable(level);
+    hprintln!("信号量测试线程已启动");
+}
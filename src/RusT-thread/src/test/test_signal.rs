@@ -0,0 +1,125 @@
+//! 信号测试代码
+//!
+//! 接收线程安装信号处理函数并在睡眠中被信号打断，
+//! 等待线程使用 rt_signal_wait 限时等待信号
+extern crate alloc;
+
+use crate::rtthread_rt::thread::*;
+use crate::rtthread_rt::hardware::*;
+use crate::rtthread_rt::rtdef::*;
+use crate::rtthread_rt::ipc::{rt_sem_create, rt_sem_take};
+use cortex_m_semihosting::hprintln;
+use lazy_static::lazy_static;
+use alloc::sync::Arc;
+use spin::Mutex;
+
+const SIG_CANCEL: i32 = 1;
+const SIG_WAKE: i32 = 2;
+
+lazy_static! {
+    static ref HANDLER_THREAD: Mutex<Option<Arc<RtThread>>> = Mutex::new(None);
+    static ref WAIT_THREAD: Mutex<Option<Arc<RtThread>>> = Mutex::new(None);
+}
+
+/// 信号处理函数，在接收线程的上下文中执行
+fn cancel_handler(signo: i32) {
+    let thread = rt_thread_self().unwrap();
+    hprintln!("cancel_handler: signal {} handled in thread {}", signo, thread.thread_name());
+}
+
+/// 接收线程：安装处理函数后进入睡眠，等待信号
+pub extern "C" fn sig_handler_thread(arg: usize) -> () {
+    rt_signal_install(SIG_CANCEL, RtSigAction::Handler(cancel_handler));
+    rt_signal_unmask(SIG_CANCEL);
+    rt_thread_sleep(rt_thread_self().unwrap(), 2000);
+    let error = rt_thread_self().unwrap().inner.exclusive_access().error;
+    hprintln!("sig_handler_thread: woke up, error: {} (expect RT_EINTR={})", error, RT_EINTR);
+    rt_thread_delete(rt_thread_self().unwrap());
+}
+
+/// 等待线程：先限时等待（应超时），再等待发送线程的信号
+pub extern "C" fn sig_wait_thread(arg: usize) -> () {
+    let mut si = RtSigInfo::default();
+    rt_signal_unmask(SIG_WAKE);
+    let result = rt_signal_wait(1 << SIG_WAKE, &mut si, 100);
+    hprintln!("sig_wait_thread: wait result: {} (expect RT_ETIMEOUT={})", result, RT_ETIMEOUT);
+    let result = rt_signal_wait(1 << SIG_WAKE, &mut si, RT_WAITING_FOREVER);
+    hprintln!("sig_wait_thread: wait result: {}, signo: {} (expect {})", result, si.si_signo, SIG_WAKE);
+    rt_thread_delete(rt_thread_self().unwrap());
+}
+
+/// 发送线程
+pub extern "C" fn sig_kill_thread(arg: usize) -> () {
+    rt_thread_sleep(rt_thread_self().unwrap(), 500);
+    if let Some(thread) = HANDLER_THREAD.lock().clone() {
+        hprintln!("sig_kill_thread: kill {}", thread.thread_name());
+        rt_thread_kill(thread, SIG_CANCEL);
+    }
+    if let Some(thread) = WAIT_THREAD.lock().clone() {
+        hprintln!("sig_kill_thread: kill {}", thread.thread_name());
+        rt_thread_kill(thread, SIG_WAKE);
+    }
+    rt_thread_delete(rt_thread_self().unwrap());
+}
+
+/// 运行信号测试
+pub fn run_signal_test() {
+    hprintln!("开始信号测试...");
+    let handler_thread = rt_thread_create("sig_handler", sig_handler_thread as usize, 0, 2*1024, 10, 100).unwrap();
+    let wait_thread = rt_thread_create("sig_wait", sig_wait_thread as usize, 0, 2*1024, 11, 100).unwrap();
+    let kill_thread = rt_thread_create("sig_kill", sig_kill_thread as usize, 0, 2*1024, 12, 100).unwrap();
+    *HANDLER_THREAD.lock() = Some(handler_thread.clone());
+    *WAIT_THREAD.lock() = Some(wait_thread.clone());
+
+    let level = rt_hw_interrupt_disable();
+    rt_thread_startup(handler_thread);
+    rt_thread_startup(wait_thread);
+    rt_thread_startup(kill_thread);
+    rt_hw_interrupt_enable(level);
+    hprintln!("信号测试线程已启动");
+}
+
+/// 信号打断 IPC 限时等待：等待返回 RT_EINTR 后，超时定时器应已停止并从线程上移除
+pub fn run_signal_ipc_test() {
+    hprintln!("开始信号打断 IPC 等待测试...");
+    let sem = rt_sem_create("sig_sem", 0).unwrap();
+    let waiter = rt_thread_spawn("sig_ipc", 2 * 1024, 10, 100, move || {
+        rt_signal_unmask(SIG_WAKE);
+        let result = rt_sem_take(sem, 2000);
+        assert!(result == RT_EINTR, "被信号打断的等待应返回 RT_EINTR: {}", result);
+        let thread = rt_thread_self().unwrap();
+        assert!(thread.inner.exclusive_access().timer.is_none(), "IPC 超时定时器未被移除");
+        assert!(rt_thread_sleep(thread, 10) == RT_EOK, "打断后无法睡眠");
+        hprintln!("信号打断 IPC 等待测试通过");
+    }).unwrap();
+    rt_thread_spawn("sig_ipc_kill", 2 * 1024, 11, 100, move || {
+        rt_thread_sleep(rt_thread_self().unwrap(), 500);
+        rt_thread_kill(waiter, SIG_WAKE);
+    }).unwrap();
+}
+
+/// 等待中收到不在等待集合中的信号：执行其处理函数后继续等待，不应返回 RT_EOK
+pub fn run_signal_wait_other_test() {
+    hprintln!("开始信号等待集合测试...");
+    let waiter = rt_thread_spawn("sig_set", 2 * 1024, 10, 100, || {
+        rt_signal_install(SIG_CANCEL, RtSigAction::Handler(cancel_handler));
+        rt_signal_unmask(SIG_CANCEL);
+        rt_signal_unmask(SIG_WAKE);
+        let mut si = RtSigInfo::default();
+        // 只收到 SIG_CANCEL，等待应超时
+        let result = rt_signal_wait(1 << SIG_WAKE, &mut si, 300);
+        assert!(result == RT_ETIMEOUT, "被其他信号唤醒后不应返回: {}", result);
+        // 先收到 SIG_CANCEL，随后收到 SIG_WAKE
+        let result = rt_signal_wait(1 << SIG_WAKE, &mut si, 2000);
+        assert!(result == RT_EOK && si.si_signo == SIG_WAKE, "未收到等待的信号: {}, {}", result, si.si_signo);
+        hprintln!("信号等待集合测试通过");
+    }).unwrap();
+    rt_thread_spawn("sig_set_kill", 2 * 1024, 11, 100, move || {
+        rt_thread_sleep(rt_thread_self().unwrap(), 100);
+        rt_thread_kill(waiter.clone(), SIG_CANCEL);
+        rt_thread_sleep(rt_thread_self().unwrap(), 400);
+        rt_thread_kill(waiter.clone(), SIG_CANCEL);
+        rt_thread_sleep(rt_thread_self().unwrap(), 100);
+        rt_thread_kill(waiter, SIG_WAKE);
+    }).unwrap();
+}
@@ -0,0 +1,46 @@
+//! slab 分配器测试代码
+//!
+//! 需使用 `--no-default-features --features "embedded slab"` 编译
+extern crate alloc;
+use alloc::alloc::{alloc, dealloc, Layout};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use cortex_m_semihosting::hprintln;
+
+use crate::rtthread_rt::mem::{rt_slab_cache_stats, rt_slab_info};
+
+//-----------------测试：小块分配与复用-----------------
+pub fn test_slab_small() {
+    hprintln!("test_slab_small");
+    let before = rt_slab_cache_stats();
+
+    // 同一大小类别的块释放后应被立即复用
+    let a = Box::new([0u32; 6]);
+    let addr = &*a as *const _ as usize;
+    drop(a);
+    let b = Box::new([1u32; 6]);
+    hprintln!("reuse: {} (expect true)", &*b as *const _ as usize == addr);
+    drop(b);
+
+    let boxes: Vec<Box<u64>> = (0..64).map(Box::new).collect();
+    let during = rt_slab_cache_stats();
+    for (old, new) in before.iter().zip(during.iter()) {
+        if new.used != old.used {
+            hprintln!("cache {}: used {} -> {}", new.size, old.used, new.used);
+        }
+    }
+    drop(boxes);
+    rt_slab_info();
+    hprintln!("test_slab_small done\n\n\n");
+}
+
+//-----------------测试：大块与对齐-----------------
+pub fn test_slab_large() {
+    hprintln!("test_slab_large");
+    let layout = Layout::from_size_align(2048, 2048).unwrap();
+    let p = unsafe { alloc(layout) };
+    hprintln!("p: {:p}, aligned: {} (expect true)", p, p as usize % 2048 == 0);
+    unsafe { dealloc(p, layout) };
+    rt_slab_info();
+    hprintln!("test_slab_large done\n\n\n");
+}
@@ -0,0 +1,76 @@
+//! 线程栈测试代码
+//!
+//! 栈溢出检查：在 1KB 的栈上递归调用，每层占用约 128 字节并让出 CPU，
+//! 调度器切换线程时应检测到栈溢出并进入 MemoryManagement 异常，打印线程名
+//!
+//! 栈使用量统计：两个线程分别递归到不同深度，之后打印线程列表比较最大使用量
+extern crate alloc;
+
+use crate::rtthread_rt::thread::*;
+use crate::rtthread_rt::hardware::*;
+use cortex_m_semihosting::hprintln;
+
+/// 递归调用，每层在栈上占用一个 128 字节的数组
+#[inline(never)]
+fn recurse(depth: usize) -> usize {
+    let buffer = [depth as u8; 128];
+    hprintln!("stack_overflow_thread: depth {}", depth);
+    rt_thread_yield();
+    core::hint::black_box(&buffer);
+    buffer[0] as usize + recurse(depth + 1)
+}
+
+/// 不断加深递归直到栈溢出
+pub extern "C" fn stack_overflow_thread(arg: usize) -> () {
+    recurse(0);
+}
+
+/// 运行栈溢出检查测试（预期输出 "thread: stack_ovf stack overflow"）
+pub fn run_stack_overflow_test() {
+    hprintln!("开始栈溢出检查测试...");
+    let thread = rt_thread_create("stack_ovf", stack_overflow_thread as usize, 0, 1024, 10, 10).unwrap();
+
+    let level = rt_hw_interrupt_disable();
+    rt_thread_startup(thread);
+    rt_hw_interrupt_enable(level);
+}
+
+/// 递归到指定深度后返回
+#[inline(never)]
+fn recurse_to(depth: usize) -> usize {
+    let buffer = [depth as u8; 128];
+    core::hint::black_box(&buffer);
+    if depth == 0 {
+        return 0;
+    }
+    buffer[0] as usize + recurse_to(depth - 1)
+}
+
+/// 浅递归线程
+pub extern "C" fn stack_shallow_thread(arg: usize) -> () {
+    recurse_to(1);
+    let thread = rt_thread_self().unwrap();
+    hprintln!("stack_shallow_thread: max used {} / {}", thread.stack_max_used(), thread.stack_size());
+    rt_thread_suspend(thread);
+}
+
+/// 深递归线程
+pub extern "C" fn stack_deep_thread(arg: usize) -> () {
+    recurse_to(8);
+    let thread = rt_thread_self().unwrap();
+    hprintln!("stack_deep_thread: max used {} / {}", thread.stack_max_used(), thread.stack_size());
+    list_thread();
+    rt_thread_suspend(thread);
+}
+
+/// 运行栈使用量统计测试（深递归线程的最大使用量应明显更大）
+pub fn run_stack_usage_test() {
+    hprintln!("开始栈使用量统计测试...");
+    let shallow = rt_thread_create("stack_shallow", stack_shallow_thread as usize, 0, 2*1024, 10, 10).unwrap();
+    let deep = rt_thread_create("stack_deep", stack_deep_thread as usize, 0, 2*1024, 11, 10).unwrap();
+
+    let level = rt_hw_interrupt_disable();
+    rt_thread_startup(shallow);
+    rt_thread_startup(deep);
+    rt_hw_interrupt_enable(level);
+}
@@ -4,6 +4,9 @@
 //-------------测试1：上下文切换--------------------------------
 
 
+extern crate alloc;
+
+use alloc::sync::Arc;
 use cortex_m_semihosting::hprintln;
 // use core::arch::asm;
 // use lazy_static::lazy_static;
@@ -12,6 +15,8 @@ use crate::rtthread_rt::rtdef::*;
 use crate::rtthread_rt::rtconfig::*;
 use crate::rtthread_rt::thread::*;
 use crate::rtthread_rt::hardware::*;
+use crate::rtthread_rt::ipc::*;
+use crate::rtthread_rt::mem::allocator::heap_range;
 
 pub extern "C" fn thread1(arg: usize) -> () {
     let mut i = arg;
@@ -114,10 +119,11 @@ pub fn test_thread_create_and_control() {
     let thread = rt_thread_create(
         "test1",
         thread1 as usize,
+        0,
         KERNEL_STACK_SIZE,
         5,
         10
-    );
+    ).unwrap();
     // 验证线程创建是否成功
     assert!(thread.inner.exclusive_access().stat.get_stat() == (ThreadState::Init as u8), "线程创建状态错误");
     assert!(thread.inner.exclusive_access().current_priority == 5, "线程优先级设置错误");
@@ -139,10 +145,11 @@ pub fn test_thread_suspend_resume() {
     let thread = rt_thread_create(
         "test2",
         thread2 as usize,
+        0,
         KERNEL_STACK_SIZE,
         6,
         10
-    );
+    ).unwrap();
     
     // 启动线程
     rt_thread_startup(thread.clone());
@@ -167,10 +174,11 @@ pub fn test_thread_sleep() {
     let thread = rt_thread_create(
         "test3",
         thread1 as usize,
+        0,
         KERNEL_STACK_SIZE,
         7,
         10
-    );
+    ).unwrap();
     
     // 启动线程
     rt_thread_startup(thread.clone());
@@ -190,10 +198,11 @@ pub fn test_thread_priority() {
     let thread = rt_thread_create(
         "test4",
         thread2 as usize,
+        0,
         KERNEL_STACK_SIZE,
         8,
         10
-    );
+    ).unwrap();
     
     // 启动线程
     rt_thread_startup(thread.clone());
@@ -206,6 +215,250 @@ pub fn test_thread_priority() {
     hprintln!("线程优先级修改测试完成");
 }
 
+// 测试线程退出：入口函数直接返回的短生命周期线程
+pub extern "C" fn worker_thread(arg: usize) -> () {
+    hprintln!("worker_thread: {} done, returning", arg);
+}
+
+pub fn test_thread_exit() {
+    hprintln!("开始测试线程退出...");
+
+    for _ in 0..3 {
+        let thread = rt_thread_create(
+            "worker",
+            worker_thread as usize,
+            0,
+            KERNEL_STACK_SIZE,
+            9,
+            10
+        ).unwrap();
+        rt_thread_startup(thread.clone());
+        // 入口函数返回后经 rt_thread_exit 进入关闭状态
+        assert!(thread.inner.exclusive_access().stat.get_stat() == (ThreadState::Close as u8), "线程未正确退出");
+    }
+
+    hprintln!("线程退出测试完成");
+}
+
+// 测试线程回收：反复创建、退出或删除线程，空闲线程回收后线程列表中不应残留
+pub extern "C" fn sleeper_thread(arg: usize) -> () {
+    rt_thread_sleep(rt_thread_self().unwrap(), 1000);
+}
+
+pub fn test_thread_reclaim() {
+    hprintln!("开始测试线程回收...");
+
+    for _ in 0..50 {
+        // 入口函数返回的线程
+        let worker = rt_thread_create("worker", worker_thread as usize, 0, KERNEL_STACK_SIZE, 9, 10).unwrap();
+        rt_thread_startup(worker);
+        // 睡眠中被删除的线程
+        let sleeper = rt_thread_create("sleeper", sleeper_thread as usize, 0, KERNEL_STACK_SIZE, 9, 10).unwrap();
+        rt_thread_startup(sleeper.clone());
+        rt_thread_delete(sleeper.clone());
+        assert!(sleeper.inner.exclusive_access().timer.is_none(), "睡眠定时器未被释放");
+        // 让空闲线程运行，回收上面的线程
+        rt_thread_sleep(rt_thread_self().unwrap(), 10);
+        assert!(sleeper.stack_size() == 0, "线程栈未被回收");
+    }
+    // 线程列表中不应有 worker 或 sleeper
+    list_thread();
+
+    hprintln!("线程回收测试完成");
+}
+
+// 测试删除阻塞在 IPC 上的线程：线程应被移出等待队列，mutex 释放时不会转交给它
+pub fn test_thread_delete_waiting() {
+    hprintln!("开始测试删除等待中的线程...");
+
+    let mutex = rt_mutex_create("del_mutex");
+    assert!(rt_mutex_take(mutex.clone(), RT_WAITING_FOREVER) == RT_EOK, "mutex 获取失败");
+    let waiter_mutex = mutex.clone();
+    let waiter = rt_thread_spawn("del_waiter", KERNEL_STACK_SIZE, 8, 10, move || {
+        rt_mutex_take(waiter_mutex.clone(), RT_WAITING_FOREVER);
+        rt_mutex_release(waiter_mutex);
+    }).unwrap();
+    let self_thread = rt_thread_self().unwrap();
+    let base_priority = self_thread.inner.exclusive_access().current_priority;
+    // 让等待线程阻塞在 mutex 上
+    rt_thread_sleep(self_thread.clone(), 10);
+    rt_thread_delete(waiter);
+    assert!(self_thread.inner.exclusive_access().current_priority == base_priority, "等待者被删除后持有者仍保持继承的优先级");
+    rt_mutex_release(mutex.clone());
+    assert!(mutex.inner.exclusive_access().owner.is_none(), "mutex 被转交给已删除的线程");
+
+    // 持有者被删除时，mutex 转交给等待者
+    let owned = rt_mutex_create("del_owned");
+    let owner_mutex = owned.clone();
+    let owner = rt_thread_spawn("del_owner", KERNEL_STACK_SIZE, 8, 10, move || {
+        rt_mutex_take(owner_mutex, RT_WAITING_FOREVER);
+        rt_thread_delay(10000);
+    }).unwrap();
+    rt_thread_sleep(self_thread.clone(), 10);
+    rt_thread_spawn("del_killer", KERNEL_STACK_SIZE, 8, 10, move || {
+        rt_thread_delay(50);
+        rt_thread_delete(owner);
+    }).unwrap();
+    assert!(rt_mutex_take(owned.clone(), 1000) == RT_EOK, "持有者被删除后 mutex 未被转交");
+    rt_mutex_release(owned);
+
+    hprintln!("删除等待中的线程测试完成");
+}
+
+// 测试线程参数与闭包线程：同一个入口函数以不同参数启动多个实例
+pub extern "C" fn param_thread(arg: usize) -> () {
+    hprintln!("param_thread: arg = {}", arg);
+}
+
+pub fn test_thread_parameter() {
+    hprintln!("开始测试线程参数...");
+
+    // 同名且未退出的线程会被拒绝，因此每个线程使用不同的名称
+    for arg in 1..=3 {
+        let thread = rt_thread_create(&alloc::format!("param{}", arg), param_thread as usize, arg, KERNEL_STACK_SIZE, 9, 10).unwrap();
+        rt_thread_startup(thread);
+    }
+
+    for id in 1..=3 {
+        let period = id * 10;
+        rt_thread_spawn(&alloc::format!("spawn{}", id), KERNEL_STACK_SIZE, 9, 10, move || {
+            hprintln!("spawned thread {}: period = {}", id, period);
+        }).unwrap();
+    }
+
+    hprintln!("线程参数测试完成");
+}
+
+// 测试静态线程：线程对象与栈都位于静态缓冲区，不在堆上
+static mut STATIC_THREAD_STORAGE: RtThreadStorage = RtThreadStorage::new();
+static mut STATIC_THREAD_STACK: [u8; 1024] = [0; 1024];
+
+pub extern "C" fn static_thread(arg: usize) -> () {
+    let thread = rt_thread_self().unwrap();
+    let bottom = thread.inner.exclusive_access().kernel_stack.bottom();
+    hprintln!("static_thread: arg = {}, stack bottom = 0x{:x}", arg, bottom);
+
+    // 睡眠、延时与 IPC 超时重复使用线程创建时的定时器
+    let storage = unsafe { (*(&raw const STATIC_THREAD_STORAGE)).range() };
+    let (timeout_timer, delay_timer) = thread.inner.exclusive_session(|inner| {
+        (inner.timeout_timer.clone().unwrap(), inner.delay_timer.clone().unwrap())
+    });
+    assert!(storage.contains(&(Arc::as_ptr(&timeout_timer) as usize)), "超时定时器不在静态存储空间中");
+    assert!(storage.contains(&(Arc::as_ptr(&delay_timer) as usize)), "延时定时器不在静态存储空间中");
+    drop(timeout_timer);
+    drop(delay_timer);
+    assert!(rt_thread_delay(10) == RT_EOK, "静态线程延时失败");
+    assert!(rt_thread_sleep(thread.clone(), 10) == RT_EOK, "静态线程睡眠失败");
+    let sem = rt_sem_create("static_sem", 0).unwrap();
+    assert!(rt_sem_take(sem.clone(), 10) == RT_ETIMEOUT, "静态线程等待信号量应超时");
+    assert!(rt_sem_take(sem, 10) == RT_ETIMEOUT, "静态线程再次等待信号量应超时");
+    assert!(thread.inner.exclusive_access().timer.is_none(), "超时定时器未被取走");
+    hprintln!("静态线程测试通过");
+}
+
+pub fn test_thread_init() {
+    hprintln!("开始测试静态线程...");
+
+    let storage = unsafe { &mut *(&raw mut STATIC_THREAD_STORAGE) };
+    let stack = unsafe { &mut *(&raw mut STATIC_THREAD_STACK) };
+    let thread = rt_thread_init(storage, "static", static_thread as usize, 42, stack, 9, 10).unwrap();
+    let storage = unsafe { (*(&raw const STATIC_THREAD_STORAGE)).range() };
+    assert!(storage.contains(&(Arc::as_ptr(&thread) as usize)), "线程对象不在静态存储空间中");
+    let (heap_start, heap_size) = heap_range();
+    assert!(!(heap_start..heap_start + heap_size).contains(&(Arc::as_ptr(&thread) as usize)), "线程对象位于堆上");
+    rt_thread_startup(thread);
+
+    hprintln!("静态线程已启动（栈底应位于 STATIC_THREAD_STACK 0x{:x} 内）", &raw const STATIC_THREAD_STACK as usize);
+}
+
+// 测试线程创建参数检查
+static mut INVALID_THREAD_STORAGE: RtThreadStorage = RtThreadStorage::new();
+static mut INVALID_THREAD_STACK: [u8; 1024] = [0; 1024];
+
+pub fn test_thread_create_invalid() {
+    hprintln!("开始测试线程创建参数检查...");
+
+    let result = rt_thread_create("bad_stack", thread1 as usize, 0, 1000, 9, 10);
+    assert!(result.err() == Some(RtError::InvalidArgument), "非 2 的幂的栈大小未被拒绝");
+    let result = rt_thread_create("bad_stack", thread1 as usize, 0, 0, 9, 10);
+    assert!(result.err() == Some(RtError::InvalidArgument), "大小为0的栈未被拒绝");
+    let result = rt_thread_create("bad_stack", thread1 as usize, 0, 64, 9, 10);
+    assert!(result.err() == Some(RtError::InvalidArgument), "过小的栈未被拒绝");
+    let result = rt_thread_create("bad_prio", thread1 as usize, 0, KERNEL_STACK_SIZE, RT_THREAD_PRIORITY_MAX, 10);
+    assert!(result.err() == Some(RtError::InvalidArgument), "过大的优先级未被拒绝");
+    let result = rt_thread_create("bad_tick", thread1 as usize, 0, KERNEL_STACK_SIZE, 9, 0);
+    assert!(result.err() == Some(RtError::InvalidArgument), "为0的时间片未被拒绝");
+
+    // 静态线程参数不合法时不应改写调用者的栈缓冲区
+    let storage = unsafe { &mut *(&raw mut INVALID_THREAD_STORAGE) };
+    let stack = unsafe { &mut *(&raw mut INVALID_THREAD_STACK) };
+    let result = rt_thread_init(storage, "bad_init", thread1 as usize, 0, stack, RT_THREAD_PRIORITY_MAX, 10);
+    assert!(result.err() == Some(RtError::InvalidArgument), "过大的优先级未被拒绝");
+    assert!(unsafe { (*(&raw const INVALID_THREAD_STACK)).iter().all(|&b| b == 0) }, "参数检查前改写了栈缓冲区");
+
+    let first = rt_thread_create("dup", thread1 as usize, 0, KERNEL_STACK_SIZE, 9, 10);
+    assert!(first.is_ok(), "线程创建失败");
+    let result = rt_thread_create("dup", thread1 as usize, 0, KERNEL_STACK_SIZE, 9, 10);
+    assert!(result.err() == Some(RtError::Busy), "同名线程未被拒绝");
+    rt_thread_delete(first.unwrap());
+
+    hprintln!("线程创建参数检查测试完成");
+}
+
+// 测试固定周期延时：线程每次执行一段耗时工作，唤醒时刻仍保持固定相位
+pub fn test_thread_delay_until() {
+    hprintln!("开始测试固定周期延时...");
+
+    rt_thread_spawn("delay_until", KERNEL_STACK_SIZE, 9, 10, || {
+        let period = 1000;
+        let mut last_wake = crate::rtthread_rt::timer::rt_tick_get();
+        let start = last_wake;
+        for i in 1..=5 {
+            rt_thread_delay_until(&mut last_wake, period);
+            let phase = crate::rtthread_rt::timer::rt_tick_get().wrapping_sub(start);
+            hprintln!("delay_until: 第 {} 次唤醒, 距开始 {} tick (expect {})", i, phase, i * period);
+            // 模拟控制循环中的耗时工作
+            for _ in 0..1000 {
+                cortex_m::asm::nop();
+            }
+        }
+        // 耗时超过 2 个周期，错过唤醒时刻后相位不变
+        let busy_start = crate::rtthread_rt::timer::rt_tick_get();
+        while crate::rtthread_rt::timer::rt_tick_get().wrapping_sub(busy_start) < period * 5 / 2 {
+            cortex_m::asm::nop();
+        }
+        rt_thread_delay_until(&mut last_wake, period);
+        assert!(last_wake.wrapping_sub(start) % period == 0, "错过唤醒时刻后相位改变");
+    }).unwrap();
+
+    hprintln!("固定周期延时测试完成");
+}
+
+// 测试当前线程延时：正常结束、0 tick 让出，以及被 rt_thread_resume 提前唤醒
+pub fn test_thread_delay() {
+    hprintln!("开始测试线程延时...");
+
+    let delayed = rt_thread_spawn("delay", KERNEL_STACK_SIZE, 9, 10, || {
+        for _ in 0..3 {
+            let result = rt_thread_delay(100);
+            hprintln!("delay: rt_thread_delay(100) = {} (expect RT_EOK={})", result, RT_EOK);
+        }
+        hprintln!("delay: rt_thread_delay(0) = {} (expect RT_EOK={})", rt_thread_delay(0), RT_EOK);
+        hprintln!("delay: rt_thread_mdelay(1) = {} (expect RT_EOK={})", rt_thread_mdelay(1), RT_EOK);
+        let result = rt_thread_mdelay(10000);
+        assert!(result == RT_EINTR, "提前唤醒的延时应返回 RT_EINTR");
+        hprintln!("delay: 被提前唤醒 = {}", result);
+    }).unwrap();
+
+    let waker = rt_thread_spawn("delay_wake", KERNEL_STACK_SIZE, 8, 10, move || {
+        rt_thread_mdelay(1000);
+        rt_thread_resume(delayed);
+    });
+    assert!(waker.is_ok(), "线程创建失败");
+
+    hprintln!("线程延时测试完成");
+}
+
 // 运行所有线程测试
 pub fn run_all_thread_tests() {
     hprintln!("开始运行所有线程测试...");
@@ -214,6 +467,14 @@ pub fn run_all_thread_tests() {
     test_thread_suspend_resume();
     test_thread_sleep();
     test_thread_priority();
+    test_thread_exit();
+    test_thread_reclaim();
+    test_thread_delete_waiting();
+    test_thread_parameter();
+    test_thread_init();
+    test_thread_create_invalid();
+    test_thread_delay_until();
+    test_thread_delay();
     test_thread_context_switch();
     test_thread_context_switch_from_to();
     
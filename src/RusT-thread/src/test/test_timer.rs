@@ -4,7 +4,16 @@ use spin::Mutex;
 use alloc::boxed::Box;
 use cortex_m_semihosting::hprintln;
 use cortex_m::asm;
-use crate::rtthread_rt::timer::{RtTimer, rt_timer_start, rt_tick_get, rt_timer_stop};
+use alloc::vec::Vec;
+use core::sync::atomic::{AtomicU32, Ordering};
+use crate::rtthread_rt::timer::{TimerHandle, RT_TIMER_FLAG_ACTIVATED};
+use crate::rtthread_rt::timer::{RtTimer, rt_timer_start, rt_tick_get, rt_timer_stop, RT_TIMER_FLAG_SOFT_TIMER};
+use crate::rtthread_rt::timer::{rt_timer_control, TimerControlCmd, RT_TIMER_FLAG_PERIODIC};
+use crate::rtthread_rt::timer::{TimerSkipList, Duration, rt_time_now, rt_tick_get64, rt_tick_get_millisecond, rt_tick_from_millisecond, rt_tick_to_ms, rt_tick_to_us, rt_tick_to_ns};
+use crate::rtthread_rt::ipc::{rt_sem_create, rt_sem_take, rt_sem_release};
+use crate::rtthread_rt::rtdef::{RT_EOK, RT_WAITING_FOREVER};
+use crate::rtthread_rt::thread::{rt_thread_spawn, rt_thread_sleep, rt_thread_self};
+use crate::rtthread_rt::rtconfig::{RT_USING_TICKLESS, RT_TICK_PER_SECOND};
 
 struct SharedCounter {
     count: u32,
@@ -60,6 +69,13 @@ pub fn example_timer() {
 pub fn run_all_timer_tests() {
     // single_timer_test();
     periodic_timer_test();
+    // soft_timer_test();
+    // soft_timer_block_stop_test();
+    // tickless_sleep_test();
+    // time_base_test();
+    // skip_list_test();
+    // timer_control_test();
+    // timer_restart_in_callback_test();
 }
 
 /// 简单的定时器测试
@@ -122,3 +138,221 @@ pub fn periodic_timer_test() {
     rt_timer_start(timer.clone());
 }
 
+/// 软定时器测试
+/// 回调运行在定时器线程中，可以分配内存并释放信号量唤醒等待线程
+pub fn soft_timer_test() {
+    let sem = rt_sem_create("soft_sem", 0).unwrap();
+    let sem_for_callback = sem.clone();
+    let mut history: Vec<u32> = Vec::new();
+
+    let timer = Arc::new(Mutex::new(RtTimer::new(
+        "soft_timer_test",
+        0,
+        0x2 | RT_TIMER_FLAG_SOFT_TIMER, // 周期软定时器
+        None,
+        500,
+        500,
+    )));
+    let timer_clone = timer.clone();
+    timer.lock().set_timeout_callback(move || {
+        history.push(rt_tick_get());
+        hprintln!("软定时器测试：回调执行 at tick: {}, count: {}", rt_tick_get(), history.len());
+        rt_sem_release(sem_for_callback.clone());
+        if history.len() >= 3 {
+            rt_timer_stop(&timer_clone);
+        }
+    });
+
+    rt_thread_spawn("soft_wait", 2 * 1024, 10, 100, move || {
+        for i in 0..3 {
+            let result = rt_sem_take(sem.clone(), RT_WAITING_FOREVER);
+            hprintln!("软定时器测试：第 {} 次被唤醒, result: {} (expect RT_EOK={})", i + 1, result, RT_EOK);
+        }
+    }).unwrap();
+
+    rt_timer_start(timer);
+}
+
+/// 软定时器回调阻塞时停止定时器
+/// 回调阻塞在信号量上期间，其他线程停止该定时器；延迟停止在 SysTick 中断中生效时
+/// 不能因回调仍占用定时器而卡死，且回调返回后定时器不再触发
+pub fn soft_timer_block_stop_test() {
+    let entered = rt_sem_create("soft_enter", 0).unwrap();
+    let gate = rt_sem_create("soft_gate", 0).unwrap();
+    let count = Arc::new(AtomicU32::new(0));
+
+    let timer: TimerHandle = Arc::new(Mutex::new(RtTimer::new(
+        "soft_block",
+        0,
+        RT_TIMER_FLAG_PERIODIC | RT_TIMER_FLAG_SOFT_TIMER,
+        None,
+        100,
+        100,
+    )));
+    let callback_entered = entered.clone();
+    let callback_gate = gate.clone();
+    let callback_count = count.clone();
+    timer.lock().set_timeout_callback(move || {
+        callback_count.fetch_add(1, Ordering::SeqCst);
+        rt_sem_release(callback_entered.clone());
+        // 阻塞在信号量上，直到停止线程放行
+        rt_sem_take(callback_gate.clone(), RT_WAITING_FOREVER);
+    });
+
+    let stop_timer = timer.clone();
+    rt_thread_spawn("soft_stop", 2 * 1024, 10, 100, move || {
+        assert!(rt_sem_take(entered.clone(), RT_WAITING_FOREVER) == RT_EOK);
+        rt_timer_stop(&stop_timer);
+        // 等待若干 tick，让延迟停止在 SysTick 中断中生效
+        rt_thread_sleep(rt_thread_self().unwrap(), 10);
+        let mut state = 0;
+        rt_timer_control(&stop_timer, TimerControlCmd::GetState(&mut state));
+        assert!(state & RT_TIMER_FLAG_ACTIVATED as u32 == 0, "回调阻塞期间定时器未被停止");
+
+        rt_sem_release(gate.clone());
+        rt_thread_sleep(rt_thread_self().unwrap(), 500);
+        assert!(count.load(Ordering::SeqCst) == 1, "停止后的定时器仍被触发");
+        hprintln!("软定时器阻塞停止测试通过");
+    }).unwrap();
+
+    rt_timer_start(timer);
+}
+
+/// tickless 空闲测试（需开启 RT_USING_TICKLESS）
+/// 线程睡眠期间只有空闲线程运行，系统进入 tickless 睡眠；唤醒后 tick 应已补齐
+pub fn tickless_sleep_test() {
+    hprintln!("tickless 测试：RT_USING_TICKLESS = {}", RT_USING_TICKLESS);
+    rt_thread_spawn("tickless", 2 * 1024, 10, 100, || {
+        for _ in 0..3 {
+            let start = rt_tick_get();
+            rt_thread_sleep(rt_thread_self().unwrap(), 100000);
+            let elapsed = rt_tick_get().wrapping_sub(start);
+            hprintln!("tickless 测试：睡眠 100000 tick，实际经过 {} tick", elapsed);
+        }
+    }).unwrap();
+}
+
+/// 64 位时间基准与时间换算测试
+pub fn time_base_test() {
+    let tick = rt_tick_from_millisecond(1500);
+    assert!(rt_tick_to_ms(tick as u64) == 1500, "毫秒与 tick 往返换算错误");
+    // 超过 32 位的 tick 换算时不应截断
+    let large = u32::MAX as u64 * 3;
+    assert!(rt_tick_to_ms(large) == large * 1000 / RT_TICK_PER_SECOND as u64, "超过 32 位的 tick 换算错误");
+    assert!(rt_tick_to_us(RT_TICK_PER_SECOND as u64) == 1_000_000, "tick 与微秒换算错误");
+    assert!(rt_tick_to_ns(large) == (large as u128 * 1_000_000_000 / RT_TICK_PER_SECOND as u128) as u64, "超过 32 位的 tick 换算纳秒错误");
+
+    let start = rt_time_now();
+    rt_thread_spawn("time_base", 2 * 1024, 10, 100, move || {
+        rt_thread_sleep(rt_thread_self().unwrap(), rt_tick_from_millisecond(200) as usize);
+        let elapsed: Duration = rt_time_now() - start;
+        assert!(elapsed.to_millis() >= 200, "睡眠时间不足 200 ms: {} ms", elapsed.to_millis());
+        assert!(rt_tick_get64() >= elapsed.ticks(), "64 位 tick 小于经过的时间");
+        hprintln!("时间基准测试通过：睡眠 200 ms，实际经过 {} ms，uptime: {} ms", elapsed.to_millis(), rt_tick_get_millisecond());
+    }).unwrap();
+}
+
+/// 定时器跳表测试
+/// 乱序插入后按超时时间取出，并验证按节点下标删除
+pub fn skip_list_test() {
+    let mut list = TimerSkipList::new();
+    let mut nodes = Vec::new();
+    for i in 0..200u64 {
+        let deadline = (i * 7919) % 200; // 0..200 的一个排列
+        let timer = Arc::new(Mutex::new(RtTimer::new("skip", 0, 0, None, 0, 0)));
+        nodes.push((list.insert(timer, deadline), deadline));
+    }
+    // 删除超时时间为奇数的节点
+    for &(index, deadline) in nodes.iter() {
+        if deadline % 2 == 1 {
+            list.remove(index);
+        }
+    }
+    assert!(list.len() == 100, "按下标删除后剩余 {} 个定时器", list.len());
+
+    let mut last = 0;
+    while let Some((index, deadline)) = list.first() {
+        assert!(deadline >= last, "跳表未按超时时间排序");
+        assert!(deadline % 2 == 0, "已删除的节点仍在跳表中");
+        last = deadline;
+        list.remove(index);
+    }
+    assert!(list.is_empty(), "清空后跳表不为空");
+    hprintln!("跳表测试通过");
+}
+
+/// 定时器控制测试：剩余时间、修改激活定时器的超时时间、读取标志与重新计时
+pub fn timer_control_test() {
+    let timer = Arc::new(Mutex::new(RtTimer::new("control_test", 0, 0x0, None, 1000, 1000)));
+    timer.lock().set_timeout_callback(|| {
+        hprintln!("定时器控制测试：超时 at tick: {}", rt_tick_get());
+    });
+
+    let mut value = 0;
+    rt_timer_control(&timer, TimerControlCmd::GetRemainTime(&mut value));
+    assert!(value == 0, "未启动的定时器剩余时间应为 0");
+
+    rt_timer_start(timer.clone());
+    rt_timer_control(&timer, TimerControlCmd::GetRemainTime(&mut value));
+    assert!(value > 0 && value <= 1000, "启动后剩余时间错误: {}", value);
+
+    // 激活状态下修改超时时间，从当前时刻起重新计时
+    rt_timer_control(&timer, TimerControlCmd::SetTime(5000));
+    rt_timer_control(&timer, TimerControlCmd::GetRemainTime(&mut value));
+    assert!(value > 1000 && value <= 5000, "SetTime 未重新计时: {}", value);
+
+    // 超过 i32::MAX 的剩余时间按 64 位超时时间计算
+    rt_timer_control(&timer, TimerControlCmd::SetTime(u32::MAX));
+    rt_timer_control(&timer, TimerControlCmd::GetRemainTime(&mut value));
+    assert!(value > i32::MAX as u32, "超过 i32::MAX 的剩余时间错误: {}", value);
+
+    rt_timer_control(&timer, TimerControlCmd::SetPeriodic);
+    rt_timer_control(&timer, TimerControlCmd::GetFlag(&mut value));
+    assert!(value & RT_TIMER_FLAG_PERIODIC as u32 != 0, "SetPeriodic 未生效");
+    rt_timer_control(&timer, TimerControlCmd::SetOneshot);
+
+    // 停止后立即 Restart，停止请求被取消
+    rt_timer_stop(&timer);
+    rt_timer_control(&timer, TimerControlCmd::Restart);
+    rt_timer_control(&timer, TimerControlCmd::GetState(&mut value));
+    assert!(value == RT_TIMER_FLAG_ACTIVATED as u32, "Restart 后定时器应处于激活状态");
+
+    // 单次定时器超时后不再处于激活状态
+    let oneshot: TimerHandle = Arc::new(Mutex::new(RtTimer::new("control_once", 0, 0x0, None, 10, 10)));
+    oneshot.lock().set_timeout_callback(|| {});
+    rt_timer_start(oneshot.clone());
+    rt_thread_spawn("control_chk", 2 * 1024, 10, 100, move || {
+        rt_thread_sleep(rt_thread_self().unwrap(), 100);
+        let mut state = 0;
+        rt_timer_control(&oneshot, TimerControlCmd::GetState(&mut state));
+        assert!(state == 0, "单次定时器超时后仍处于激活状态");
+        hprintln!("定时器控制测试通过");
+    }).unwrap();
+}
+
+/// 在定时器自身的回调中 Restart（看门狗喂狗模式）
+/// 单次定时器在前两次回调中重新计时，共应触发 3 次
+pub fn timer_restart_in_callback_test() {
+    let count = Arc::new(AtomicU32::new(0));
+    let timer: TimerHandle = Arc::new(Mutex::new(RtTimer::new("restart_cb", 0, 0x0, None, 100, 100)));
+    let callback_timer = timer.clone();
+    let callback_count = count.clone();
+    timer.lock().set_timeout_callback(move || {
+        if callback_count.fetch_add(1, Ordering::SeqCst) + 1 < 3 {
+            rt_timer_control(&callback_timer, TimerControlCmd::Restart);
+        }
+    });
+    rt_timer_start(timer.clone());
+
+    rt_thread_spawn("restart_chk", 2 * 1024, 10, 100, move || {
+        rt_thread_sleep(rt_thread_self().unwrap(), 1000);
+        assert!(count.load(Ordering::SeqCst) == 3, "回调中 Restart 未生效");
+        let mut remain = 0;
+        rt_timer_control(&timer, TimerControlCmd::GetRemainTime(&mut remain));
+        assert!(remain == 0, "最后一次回调后定时器不应再计时");
+        let mut state = 0;
+        rt_timer_control(&timer, TimerControlCmd::GetState(&mut state));
+        assert!(state == 0, "最后一次回调后定时器不应处于激活状态");
+        hprintln!("回调中 Restart 测试通过");
+    }).unwrap();
+}
@@ -3,7 +3,12 @@
 //! 使用SysTick计数器测量线程创建所需的时间
 //! 通过批量创建多个线程并计算平均值，获得更准确的测量结果
 
+extern crate alloc;
+
 use core::sync::atomic::{AtomicU32, Ordering};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
 use cortex_m_semihosting::hprintln;
 use crate::rtthread_rt::timer::clock::*;
 use crate::rtthread_rt::thread::rt_thread_create;
@@ -20,22 +25,26 @@ pub fn test_thread_creation_time(thread_count: usize) {
     hprintln!("\n======= 线程创建时间测试 =======");
     
     // 测试参数
-    let stack_size = 128;  // 线程栈大小
+    let stack_size = 256;  // 线程栈大小（RT_THREAD_STACK_SIZE_MIN）
     let priority = 10;     // 线程优先级
     let tick = 10;         // 时间片大小
     
+    // 线程名不能重复，在计时前生成
+    let names: Vec<String> = (0..thread_count).map(|i| format!("test{}", i)).collect();
+
     // 记录开始时间
     let start_ticks = rt_tick_get();
     
     // 批量创建线程
     for i in 0..thread_count {
         let thread = rt_thread_create(
-            "test",
+            &names[i],
             idle_entry as usize,
+            0,
             stack_size,
             priority,
             tick
-        );
+        ).unwrap();
     }
     
     // 记录结束时间
@@ -45,7 +54,7 @@ pub fn test_thread_creation_time(thread_count: usize) {
     // 计算每个线程的平均创建时间
     let avg_ticks_per_thread = total_ticks as f32 / thread_count as f32;
     
-    let avg_time_us = rt_tick_to_us(total_ticks) as f32 / thread_count as f32;
+    let avg_time_us = rt_tick_to_us(total_ticks as u64) as f32 / thread_count as f32;
     
     hprintln!("创建线程数量: {} 个", thread_count);
     hprintln!("总时钟周期: {} tick", total_ticks);